@@ -0,0 +1,116 @@
+//! Deserialization of large responses, through the endpoint queries and,
+//! for comparison, through an intermediate `serde_json::Value`.
+
+use bytes::Bytes;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use http::{request::Builder, Response};
+use serde_json::{json, Value};
+use url::{ParseError, Url};
+
+use scholars::client::{BaseClient, Client};
+use scholars::v1::definition::{Citation, FullPaper};
+use scholars::v1::endpoint::{GetPaper, GetPaperCitations};
+use scholars::v1::pagination::{Page, Results};
+use scholars::v1::parameter::{FullPaperField, PaperField};
+use scholars::v1::query_params::{PaperCitationsParams, PaperParams};
+
+/// A client that always returns the same response body.
+struct Static(Bytes);
+
+impl BaseClient for Static {
+    type Error = http::Error;
+
+    fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError> {
+        Url::parse(endpoint)
+    }
+}
+
+impl Client for Static {
+    fn send(&self, _: Builder, _: Vec<u8>) -> Result<Response<Bytes>, http::Error> {
+        Ok(Response::new(self.0.clone()))
+    }
+}
+
+fn paper(id: usize) -> Value {
+    json!({
+        "paperId": format!("{:040x}", id),
+        "title": "Construction of the Literature Graph in Semantic Scholar",
+        "abstract": "We describe a deployed scalable system for organizing published scientific literature into a heterogeneous graph.",
+        "year": 2018,
+        "referenceCount": 27,
+        "citationCount": 143,
+        "isOpenAccess": true,
+        "fieldsOfStudy": ["Computer Science"],
+    })
+}
+
+/// A paper with 500 authors and a 768 dimensions embedding.
+fn full_paper() -> Bytes {
+    let mut paper = paper(0);
+    let authors: Vec<_> = (0..500)
+        .map(|i| json!({"authorId": i.to_string(), "name": format!("Author {}", i)}))
+        .collect();
+    let vector: Vec<_> = (0..768).map(|i| f64::from(i) / 768.0 - 0.5).collect();
+    paper["authors"] = json!(authors);
+    paper["embedding"] = json!({"model": "specter@v0.1.1", "vector": vector});
+    Bytes::from(serde_json::to_vec(&paper).unwrap())
+}
+
+/// A page of 1000 citations.
+fn citations_page() -> Bytes {
+    let data: Vec<_> = (0..1000)
+        .map(|i| {
+            json!({
+                "contexts": ["as shown in previous work [12]"],
+                "intents": ["background"],
+                "isInfluential": i % 10 == 0,
+                "citingPaper": paper(i),
+            })
+        })
+        .collect();
+    Bytes::from(serde_json::to_vec(&json!({"offset": 0, "data": data})).unwrap())
+}
+
+fn full_paper_benchmark(c: &mut Criterion) {
+    let client = Static(full_paper());
+    let endpoint =
+        GetPaper::new(PaperParams::new(None::<Vec<FullPaperField>>).unwrap(), "p".to_owned());
+
+    let mut group = c.benchmark_group("full_paper");
+    group.bench_function("query", |b| {
+        b.iter(|| black_box(endpoint.query::<FullPaper, _>(&client).unwrap()))
+    });
+    group.bench_function("via_value", |b| {
+        b.iter(|| {
+            let value: Value = serde_json::from_slice(&client.0).unwrap();
+            black_box(serde_json::from_value::<FullPaper>(value).unwrap())
+        })
+    });
+    group.finish();
+}
+
+fn citations_benchmark(c: &mut Criterion) {
+    let client = Static(citations_page());
+    let fields = Some([PaperField::Contexts, PaperField::Intents, PaperField::IsInfluential]);
+
+    let mut group = c.benchmark_group("citations_page");
+    group.bench_function("paged", |b| {
+        b.iter(|| {
+            let params = PaperCitationsParams::new(fields, Page::default()).unwrap();
+            let endpoint = GetPaperCitations::new(params, "p".to_owned());
+            let citations = endpoint.paged::<Citation, _>(Results::All, &client);
+            black_box(citations.collect::<Result<Vec<_>, _>>().unwrap())
+        })
+    });
+    group.bench_function("via_value", |b| {
+        b.iter(|| {
+            let mut value: Value = serde_json::from_slice(&client.0).unwrap();
+            let data = value["data"].take();
+            black_box(serde_json::from_value::<Vec<Citation>>(data).unwrap())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, full_paper_benchmark, citations_benchmark);
+criterion_main!(benches);
@@ -1,5 +1,6 @@
 use scholars::v1::definition::AuthorWithPapers;
 use scholars::v1::endpoint::GetAuthor;
+use scholars::v1::error::FieldsError;
 use scholars::v1::query_params::AuthorParams;
 use scholars::v1::utils::all_author_with_papers_fields;
 
@@ -8,7 +9,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::Client::new();
-    let endpoint = GetAuthor::new(query_params(), author_id());
+    let endpoint = GetAuthor::new(query_params()?, author_id());
 
     // An async query execution through the default reqwest client implementation.
     let author: AuthorWithPapers = endpoint.query_async(&client).await?;
@@ -17,7 +18,7 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> AuthorParams {
+fn query_params() -> Result<AuthorParams, FieldsError> {
     AuthorParams::new(Some(all_author_with_papers_fields()))
 }
 
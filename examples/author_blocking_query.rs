@@ -1,5 +1,6 @@
 use scholars::v1::definition::AuthorWithPapers;
 use scholars::v1::endpoint::GetAuthor;
+use scholars::v1::error::FieldsError;
 use scholars::v1::query_params::AuthorParams;
 use scholars::v1::utils::all_author_with_papers_fields;
 
@@ -7,7 +8,7 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::blocking::Client::new();
-    let endpoint = GetAuthor::new(query_params(), author_id());
+    let endpoint = GetAuthor::new(query_params()?, author_id());
 
     // A blocking query execution through the default reqwest client implementation.
     let author: AuthorWithPapers = endpoint.query(&client)?;
@@ -16,7 +17,7 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> AuthorParams {
+fn query_params() -> Result<AuthorParams, FieldsError> {
     AuthorParams::new(Some(all_author_with_papers_fields()))
 }
 
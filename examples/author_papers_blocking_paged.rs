@@ -1,5 +1,6 @@
 use scholars::v1::definition::BasePaper;
 use scholars::v1::endpoint::GetAuthorPapers;
+use scholars::v1::error::FieldsError;
 use scholars::v1::pagination::{Page, Results};
 use scholars::v1::query_params::AuthorPapersParams;
 use scholars::v1::utils::all_paper_with_links_fields;
@@ -8,7 +9,7 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::blocking::Client::new();
-    let endpoint = GetAuthorPapers::new(query_params(), author_id());
+    let endpoint = GetAuthorPapers::new(query_params()?, author_id());
     let results = Results::Limit(68);
 
     // Collecting into a `Result<Collection<T>, E>` will
@@ -32,7 +33,7 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> AuthorPapersParams {
+fn query_params() -> Result<AuthorPapersParams, FieldsError> {
     AuthorPapersParams::new(Some(all_paper_with_links_fields()), Page::default())
 }
 
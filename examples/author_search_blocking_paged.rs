@@ -1,5 +1,6 @@
 use scholars::v1::definition::AuthorWithPapers;
 use scholars::v1::endpoint::GetAuthorSearch;
+use scholars::v1::error::FieldsError;
 use scholars::v1::pagination::{Page, Results};
 use scholars::v1::query_params::AuthorSearchParams;
 use scholars::v1::utils::all_author_with_papers_fields;
@@ -8,7 +9,7 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::blocking::Client::new();
-    let endpoint = GetAuthorSearch::new(query_params());
+    let endpoint = GetAuthorSearch::new(query_params()?);
     let results = Results::Limit(10);
 
     // Collecting into a `Result<Collection<T>, E>` will
@@ -24,7 +25,7 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> AuthorSearchParams {
+fn query_params() -> Result<AuthorSearchParams, FieldsError> {
     AuthorSearchParams::new(
         "adam smith".to_string(),
         Some(all_author_with_papers_fields()),
@@ -1,5 +1,6 @@
 use scholars::v1::definition::AuthorWithPapers;
 use scholars::v1::endpoint::GetPaperAuthors;
+use scholars::v1::error::FieldsError;
 use scholars::v1::pagination::{Page, Results};
 use scholars::v1::query_params::PaperAuthorsParams;
 use scholars::v1::utils::author_with_papers_fields_with;
@@ -11,7 +12,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::Client::new();
-    let endpoint = GetPaperAuthors::new(query_params(), paper_id());
+    let endpoint = GetPaperAuthors::new(query_params()?, paper_id());
     let results = Results::Limit(68);
 
     // Collecting into a `Result<Collection<T>, E>` will
@@ -36,7 +37,7 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> PaperAuthorsParams {
+fn query_params() -> Result<PaperAuthorsParams, FieldsError> {
     PaperAuthorsParams::new(
         Some(author_with_papers_fields_with(std::iter::empty())),
         Page::default(),
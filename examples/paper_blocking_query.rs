@@ -1,5 +1,6 @@
 use scholars::v1::definition::FullPaper;
 use scholars::v1::endpoint::GetPaper;
+use scholars::v1::error::FieldsError;
 use scholars::v1::query_params::PaperParams;
 use scholars::v1::utils::all_full_paper_fields;
 
@@ -7,7 +8,7 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::blocking::Client::new();
-    let endpoint = GetPaper::new(query_params(), paper_id());
+    let endpoint = GetPaper::new(query_params()?, paper_id());
 
     // A blocking query execution through the default reqwest client implementation.
     let paper: FullPaper = endpoint.query(&client)?;
@@ -16,7 +17,7 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> PaperParams {
+fn query_params() -> Result<PaperParams, FieldsError> {
     PaperParams::new(Some(all_full_paper_fields()))
 }
 
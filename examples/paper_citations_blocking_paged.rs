@@ -1,5 +1,6 @@
 use scholars::v1::definition::Citation;
 use scholars::v1::endpoint::GetPaperCitations;
+use scholars::v1::error::FieldsError;
 use scholars::v1::pagination::{Page, Results};
 use scholars::v1::query_params::PaperCitationsParams;
 use scholars::v1::utils::paper_fields_with;
@@ -8,7 +9,7 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::blocking::Client::new();
-    let endpoint = GetPaperCitations::new(query_params(), paper_id());
+    let endpoint = GetPaperCitations::new(query_params()?, paper_id());
     let results = Results::Limit(98);
 
     // Collecting into a `Result<Collection<T>, E>` will
@@ -32,7 +33,7 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> PaperCitationsParams {
+fn query_params() -> Result<PaperCitationsParams, FieldsError> {
     PaperCitationsParams::new(Some(paper_fields_with(std::iter::empty())), Page::default())
 }
 
@@ -0,0 +1,35 @@
+use scholars::v1::crawl::{CrawlEvent, CrawlOptions, Crawler, Direction};
+use scholars::v1::pagination::Results;
+
+fn main() -> anyhow::Result<()> {
+    env_logger::init();
+
+    let client = reqwest::blocking::Client::new();
+    let crawler = Crawler::new(seeds(), options());
+
+    // Expansion errors are reported and the crawl moves on to the next paper.
+    for event in crawler.crawl(&client) {
+        match event {
+            Ok(CrawlEvent::Node(node)) => {
+                println!("node: {:?} at depth {}", node.paper.info.title, node.depth)
+            }
+            Ok(CrawlEvent::Edge(edge)) => println!("edge: {} -> {}", edge.citing, edge.cited),
+            Err(err) => eprintln!("error: {}", err),
+        }
+    }
+
+    Ok(())
+}
+
+fn options() -> CrawlOptions {
+    CrawlOptions {
+        direction: Direction::Both,
+        max_depth: 2,
+        fan_out: Results::Limit(5),
+        ..CrawlOptions::default()
+    }
+}
+
+fn seeds() -> Vec<String> {
+    vec!["649def34f8be52c8b66281af98ae884c09aef38b".to_owned()]
+}
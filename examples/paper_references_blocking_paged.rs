@@ -1,5 +1,6 @@
 use scholars::v1::definition::Reference;
 use scholars::v1::endpoint::GetPaperReferences;
+use scholars::v1::error::FieldsError;
 use scholars::v1::pagination::{Page, Results};
 use scholars::v1::query_params::PaperReferencesParams;
 use scholars::v1::utils::paper_fields_with;
@@ -8,7 +9,7 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::blocking::Client::new();
-    let endpoint = GetPaperReferences::new(query_params(), paper_id());
+    let endpoint = GetPaperReferences::new(query_params()?, paper_id());
     let results = Results::Limit(98);
 
     // Collecting into a `Result<Collection<T>, E>` will
@@ -32,7 +33,7 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> PaperReferencesParams {
+fn query_params() -> Result<PaperReferencesParams, FieldsError> {
     PaperReferencesParams::new(Some(paper_fields_with(std::iter::empty())), Page::default())
 }
 
@@ -1,5 +1,6 @@
 use scholars::v1::definition::BasePaper;
 use scholars::v1::endpoint::GetPaperSearch;
+use scholars::v1::error::FieldsError;
 use scholars::v1::pagination::Page;
 use scholars::v1::query_params::PaperSearchParams;
 use scholars::v1::utils::all_base_paper_fields;
@@ -9,7 +10,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::Client::new();
-    let endpoint = GetPaperSearch::new(query_params());
+    let endpoint = GetPaperSearch::new(query_params()?);
 
     // An async query execution through the default reqwest client implementation.
     let papers: BasePaper = endpoint.query_async(&client).await?;
@@ -18,6 +19,6 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> PaperSearchParams {
+fn query_params() -> Result<PaperSearchParams, FieldsError> {
     PaperSearchParams::new("covid".to_string(), Some(all_base_paper_fields()), Page::default())
 }
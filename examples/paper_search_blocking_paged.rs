@@ -1,5 +1,6 @@
 use scholars::v1::definition::BasePaper;
 use scholars::v1::endpoint::GetPaperSearch;
+use scholars::v1::error::FieldsError;
 use scholars::v1::pagination::{Page, Results};
 use scholars::v1::query_params::PaperSearchParams;
 use scholars::v1::utils::all_base_paper_fields;
@@ -8,7 +9,7 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::blocking::Client::new();
-    let endpoint = GetPaperSearch::new(query_params());
+    let endpoint = GetPaperSearch::new(query_params()?);
     let results = Results::Limit(9_950);
 
     // Collecting into a `Result<Collection<T>, E>` will
@@ -32,7 +33,7 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> PaperSearchParams {
+fn query_params() -> Result<PaperSearchParams, FieldsError> {
     PaperSearchParams::new(
         "covid".to_string(),
         Some(all_base_paper_fields()),
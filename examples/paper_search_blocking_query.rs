@@ -1,5 +1,6 @@
 use scholars::v1::definition::BasePaper;
 use scholars::v1::endpoint::GetPaperSearch;
+use scholars::v1::error::FieldsError;
 use scholars::v1::pagination::Page;
 use scholars::v1::query_params::PaperSearchParams;
 use scholars::v1::utils::all_base_paper_fields;
@@ -8,7 +9,7 @@ fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let client = reqwest::blocking::Client::new();
-    let paper_search = GetPaperSearch::new(query_params());
+    let paper_search = GetPaperSearch::new(query_params()?);
 
     // A blocking query execution through the default reqwest client implementation.
     let papers: BasePaper = paper_search.query(&client)?;
@@ -17,6 +18,6 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn query_params() -> PaperSearchParams {
+fn query_params() -> Result<PaperSearchParams, FieldsError> {
     PaperSearchParams::new("covid".to_string(), Some(all_base_paper_fields()), Page::default())
 }
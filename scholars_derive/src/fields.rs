@@ -0,0 +1,244 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// How an API field is requested and where it is read from.
+pub(crate) struct FieldSpec {
+    /// The top level API field name.
+    pub(crate) name: String,
+    /// The `FullPaperField` expression that requests this field.
+    pub(crate) full: TokenStream,
+    /// The `BasePaperField` expression that requests this field, if any.
+    pub(crate) base: Option<TokenStream>,
+    /// The type of the field in `FullPaper`.
+    pub(crate) ty: TokenStream,
+    /// The path of the field in `FullPaper`.
+    pub(crate) full_path: TokenStream,
+    /// The path of the field in `BasePaper`, if any.
+    pub(crate) base_path: Option<TokenStream>,
+}
+
+/// The `PaperInfoField` variant and type of a paper info field.
+fn paper_info(name: &str) -> Option<(TokenStream, TokenStream, TokenStream)> {
+    let string = quote!(::core::option::Option<::std::string::String>);
+    let (variant, ty, path) = match name {
+        "paperId" => (quote!(PaperId), string, quote!(paper_id)),
+        "url" => (quote!(Url), string, quote!(url)),
+        "title" => (quote!(Title), string, quote!(title)),
+        "venue" => (quote!(Venue), string, quote!(venue)),
+        "year" => (quote!(Year), quote!(::core::option::Option<u64>), quote!(year)),
+        _ => return None,
+    };
+    Some((quote!(::scholars::v1::parameter::PaperInfoField::#variant), ty, path))
+}
+
+/// The `BasePaperField` variant and type of a base paper field, other than the info fields.
+fn base_paper(name: &str) -> Option<(TokenStream, TokenStream, TokenStream)> {
+    let (variant, ty, path) = match name {
+        "abstract" => (quote!(Abstract), quote!(::std::string::String), quote!(r#abstract)),
+        "externalIds" => (
+            quote!(ExternalIds),
+            quote!(::scholars::v1::definition::PaperExternalId),
+            quote!(external_ids),
+        ),
+        "referenceCount" => (quote!(ReferenceCount), quote!(u64), quote!(reference_count)),
+        "citationCount" => (quote!(CitationCount), quote!(u64), quote!(citation_count)),
+        "influentialCitationCount" => {
+            (quote!(InfluentialCitationCount), quote!(u64), quote!(influential_citation_count))
+        }
+        "isOpenAccess" => (quote!(IsOpenAccess), quote!(bool), quote!(is_open_access)),
+        "fieldsOfStudy" => (
+            quote!(FieldsOfStudy),
+            quote!(::std::collections::HashSet<::scholars::v1::definition::FieldOfStudy>),
+            quote!(fields_of_study),
+        ),
+        "publicationDate" => (
+            quote!(PublicationDate),
+            quote!(::scholars::v1::definition::PartialDate),
+            quote!(publication_date),
+        ),
+        "publicationTypes" => (
+            quote!(PublicationTypes),
+            quote!(::std::vec::Vec<::std::string::String>),
+            quote!(publication_types),
+        ),
+        "journal" => {
+            (quote!(Journal), quote!(::scholars::v1::definition::Journal), quote!(journal))
+        }
+        "publicationVenue" => (
+            quote!(PublicationVenue),
+            quote!(::scholars::v1::definition::PublicationVenue),
+            quote!(publication_venue),
+        ),
+        "openAccessPdf" => (
+            quote!(OpenAccessPdf),
+            quote!(::scholars::v1::definition::OpenAccessPdf),
+            quote!(open_access_pdf),
+        ),
+        "s2FieldsOfStudy" => (
+            quote!(S2FieldsOfStudy),
+            quote!(::std::vec::Vec<::scholars::v1::definition::S2FieldOfStudy>),
+            quote!(s2_fields_of_study),
+        ),
+        "citationStyles" => (
+            quote!(CitationStyles),
+            quote!(::scholars::v1::definition::CitationStyles),
+            quote!(citation_styles),
+        ),
+        _ => return None,
+    };
+    let ty = quote!(::core::option::Option<#ty>);
+    Some((quote!(::scholars::v1::parameter::BasePaperField::#variant), ty, path))
+}
+
+/// The `BasePaperField` variant of a sub-field of the citations or references.
+fn link(name: &str) -> Option<TokenStream> {
+    let info = match name {
+        "authors" => quote!(::scholars::v1::parameter::PaperInfoField::Authors),
+        name => match paper_info(name) {
+            Some((variant, ..)) => variant,
+            None => return base_paper(name).map(|(variant, ..)| variant),
+        },
+    };
+    Some(quote!(::scholars::v1::parameter::BasePaperField::Info(#info)))
+}
+
+/// The `AuthorField` variant of an author field.
+fn author(name: &str) -> Option<TokenStream> {
+    let info = |variant| quote!(Info(::scholars::v1::parameter::AuthorInfoField::#variant));
+    let variant = match name {
+        "authorId" => info(quote!(AuthorId)),
+        "name" => info(quote!(Name)),
+        "externalIds" => quote!(ExternalIds),
+        "url" => quote!(Url),
+        "aliases" => quote!(Aliases),
+        "affiliations" => quote!(Affiliations),
+        "homepage" => quote!(Homepage),
+        "paperCount" => quote!(PaperCount),
+        "citationCount" => quote!(CitationCount),
+        "hIndex" => quote!(HIndex),
+        _ => return None,
+    };
+    Some(quote!(::scholars::v1::parameter::AuthorField::#variant))
+}
+
+/// The `EmbeddingVersion` variant of an embedding sub-field.
+fn embedding_version(name: &str) -> Option<TokenStream> {
+    let variant = match name {
+        "specter_v1" => quote!(SpecterV1),
+        "specter_v2" => quote!(SpecterV2),
+        _ => return None,
+    };
+    Some(quote!(::scholars::v1::parameter::EmbeddingVersion::#variant))
+}
+
+/// Looks up an API field name, such as `title` or `authors.name`.
+pub(crate) fn lookup(field: &str) -> Option<FieldSpec> {
+    let full_field = quote!(::scholars::v1::parameter::FullPaperField);
+    let (name, sub_field) = match field.split_once('.') {
+        Some((name, sub_field)) => (name, Some(sub_field)),
+        None => (field, None),
+    };
+    let spec = |full, base, ty, full_path, base_path| FieldSpec {
+        name: name.to_owned(),
+        full,
+        base,
+        ty,
+        full_path,
+        base_path,
+    };
+
+    if let Some((variant, ty, path)) = paper_info(name).filter(|_| sub_field.is_none()) {
+        let base = quote!(::scholars::v1::parameter::BasePaperField::Info(#variant));
+        let full = quote!(#full_field::Base(#base));
+        return Some(spec(full, Some(base), ty, quote!(base.info.#path), Some(quote!(info.#path))));
+    }
+    if let Some((base, ty, path)) = base_paper(name).filter(|_| sub_field.is_none()) {
+        let full = quote!(#full_field::Base(#base));
+        return Some(spec(full, Some(base), ty, quote!(base.#path), Some(quote!(#path))));
+    }
+
+    let list = |item| quote!(::std::vec::Vec<::scholars::v1::definition::#item>);
+    let (full, ty, path) = match (name, sub_field) {
+        ("authors", None) => {
+            (quote!(#full_field::Authors(None)), list(quote!(Author)), quote!(authors))
+        }
+        ("authors", Some(sub_field)) => {
+            let sub_field = author(sub_field)?;
+            (quote!(#full_field::Authors(Some(#sub_field))), list(quote!(Author)), quote!(authors))
+        }
+        ("citations", None) => {
+            (quote!(#full_field::Citations(None)), list(quote!(BasePaper)), quote!(citations))
+        }
+        ("citations", Some(sub_field)) => {
+            let sub_field = link(sub_field)?;
+            let full = quote!(#full_field::Citations(Some(#sub_field)));
+            (full, list(quote!(BasePaper)), quote!(citations))
+        }
+        ("references", None) => {
+            (quote!(#full_field::References(None)), list(quote!(BasePaper)), quote!(references))
+        }
+        ("references", Some(sub_field)) => {
+            let sub_field = link(sub_field)?;
+            let full = quote!(#full_field::References(Some(#sub_field)));
+            (full, list(quote!(BasePaper)), quote!(references))
+        }
+        ("embedding", None) => (
+            quote!(#full_field::Embedding(None)),
+            quote!(::core::option::Option<::scholars::v1::definition::Embedding>),
+            quote!(embedding),
+        ),
+        ("embedding", Some(sub_field)) => {
+            let version = embedding_version(sub_field)?;
+            (
+                quote!(#full_field::Embedding(Some(#version))),
+                quote!(::core::option::Option<::scholars::v1::definition::Embedding>),
+                quote!(embedding),
+            )
+        }
+        ("tldr", None) => (
+            quote!(#full_field::Tldr),
+            quote!(::core::option::Option<::scholars::v1::definition::Tldr>),
+            quote!(tldr),
+        ),
+        _ => return None,
+    };
+    Some(spec(full, None, ty, path, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use scholars::v1::parameter::{EmbeddingVersion, FullPaperField};
+    use scholars::v1::utils::all_full_paper_fields;
+
+    use super::*;
+
+    /// The `FullPaperField` expression without its paths,
+    /// like the `Debug` output of the field it builds.
+    fn variant(tokens: &TokenStream) -> String {
+        let mut rendered = tokens.to_string().replace(' ', "");
+        let prefix = "::scholars::v1::parameter::";
+        while let Some(start) = rendered.find(prefix) {
+            let end = start + prefix.len();
+            let len = rendered[end..].find("::").expect("enum variant path") + 2;
+            rendered.replace_range(start..end + len, "");
+        }
+        rendered
+    }
+
+    // The macro keeps its own table of the API fields, this checks that it
+    // accepts every field of the parameter enums, as named by their `Display`
+    // implementation, and builds the variant their `FromStr` implementation
+    // parses, since some fields have more than one variant, like `authors`.
+    #[test]
+    fn every_paper_field_is_known() {
+        let versions = [EmbeddingVersion::SpecterV1, EmbeddingVersion::SpecterV2];
+        let embeddings = versions.iter().map(|version| FullPaperField::Embedding(Some(*version)));
+        for field in all_full_paper_fields().chain(embeddings) {
+            let name = field.to_string();
+            let spec = lookup(&name).unwrap_or_else(|| panic!("unknown field `{}`", name));
+            let parsed: FullPaperField = name.parse().unwrap();
+            assert_eq!(variant(&spec.full), format!("{:?}", parsed), "field `{}`", name);
+        }
+        assert!(lookup("authors.unknown").is_none());
+    }
+}
@@ -0,0 +1,206 @@
+#![forbid(unsafe_code)]
+
+//! Derive macros for the [`scholars`](https://docs.rs/scholars) crate.
+
+mod fields;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Lit, Meta, NestedMeta, Result};
+
+use fields::FieldSpec;
+
+/// Derives `Deserialize`, `From<FullPaper>` and `SelectFields<FullPaperField>`
+/// for a struct with named fields, so that the fields requested from the API
+/// always match the ones consumed by the struct.
+///
+/// Each struct field is matched to an API field by its name converted to
+/// camel case, or by the `#[scholars(field = "...")]` attribute. Dotted
+/// sub-fields such as `authors.name` are supported, and the attribute can
+/// be repeated to request several sub-fields into the same struct field.
+///
+/// Struct field types must be convertible with [`From`] from the type of
+/// the matching `FullPaper` field, like `Option<String>` for `title`.
+///
+/// If none of the fields is `authors`, `citations`, `references`, `embedding`
+/// or `tldr`, `From<BasePaper>` and `SelectFields<BasePaperField>` are also
+/// implemented, so the struct can be used with the paper search endpoint.
+#[proc_macro_derive(SelectFields, attributes(scholars))]
+pub fn derive_select_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// A struct field and the API fields it is deserialized from.
+struct Field {
+    ident: syn::Ident,
+    // The name of the top level API field.
+    name: String,
+    specs: Vec<FieldSpec>,
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        let msg = "`SelectFields` can't be derived for generic structs";
+        return Err(Error::new_spanned(&input.generics, msg));
+    }
+    let named = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref named) => &named.named,
+            _ => return Err(Error::new_spanned(ident, "expected a struct with named fields")),
+        },
+        _ => return Err(Error::new_spanned(ident, "expected a struct with named fields")),
+    };
+
+    let mut fields: Vec<Field> = Vec::new();
+    for field in named {
+        let ident = field.ident.clone().expect("fields must be named");
+        let specs = field_specs(field)?;
+        let name = specs[0].name.clone();
+        if fields.iter().any(|other| other.name == name) {
+            let msg = format!("the `{}` API field is already used by another field", name);
+            return Err(Error::new_spanned(field, msg));
+        }
+        fields.push(Field { ident, name, specs });
+    }
+
+    let scholars = quote!(::scholars);
+    let definition = quote!(#scholars::v1::definition);
+    let parameter = quote!(#scholars::v1::parameter);
+
+    let shadow_fields = fields.iter().map(|field| {
+        let (ident, name, ty) = (&field.ident, &field.name, &field.specs[0].ty);
+        quote!(#[serde(rename = #name, default)] #ident: #ty)
+    });
+    let from_shadow = fields.iter().map(|field| {
+        let ident = &field.ident;
+        quote!(#ident: ::core::convert::From::from(shadow.#ident))
+    });
+    let from_full = fields.iter().map(|field| {
+        let (ident, path) = (&field.ident, &field.specs[0].full_path);
+        quote!(#ident: ::core::convert::From::from(paper.#path))
+    });
+    let full_fields = fields.iter().flat_map(|field| field.specs.iter().map(|spec| &spec.full));
+
+    let base = fields.iter().all(|field| field.specs[0].base_path.is_some());
+    let base_impls = if base {
+        let from_base = fields.iter().map(|field| {
+            let (ident, path) = (&field.ident, &field.specs[0].base_path);
+            quote!(#ident: ::core::convert::From::from(paper.#path))
+        });
+        let base_fields = fields
+            .iter()
+            .flat_map(|field| field.specs.iter().filter_map(|spec| spec.base.as_ref()));
+        quote! {
+            impl ::core::convert::From<#definition::BasePaper> for #ident {
+                fn from(paper: #definition::BasePaper) -> Self {
+                    #ident { #(#from_base,)* }
+                }
+            }
+
+            impl #parameter::SelectFields<#parameter::BasePaperField> for #ident {
+                fn fields() -> ::std::vec::Vec<#parameter::BasePaperField> {
+                    ::std::vec![#(#base_fields),*]
+                }
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    Ok(quote! {
+        const _: () = {
+            use #scholars::__private::serde;
+
+            #[derive(serde::Deserialize)]
+            #[serde(crate = "::scholars::__private::serde")]
+            struct __Shadow {
+                #(#shadow_fields,)*
+            }
+
+            impl<'de> serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let shadow = <__Shadow as serde::Deserialize>::deserialize(deserializer)?;
+                    ::core::result::Result::Ok(#ident { #(#from_shadow,)* })
+                }
+            }
+
+            impl ::core::convert::From<#definition::FullPaper> for #ident {
+                fn from(paper: #definition::FullPaper) -> Self {
+                    #ident { #(#from_full,)* }
+                }
+            }
+
+            impl #parameter::SelectFields<#parameter::FullPaperField> for #ident {
+                fn fields() -> ::std::vec::Vec<#parameter::FullPaperField> {
+                    ::std::vec![#(#full_fields),*]
+                }
+            }
+
+            #base_impls
+        };
+    })
+}
+
+/// The API fields of `field`, from its attributes or its name.
+fn field_specs(field: &syn::Field) -> Result<Vec<FieldSpec>> {
+    let mut names = Vec::new();
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("scholars")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new_spanned(meta, "expected `scholars(field = \"...\")`")),
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(ref value)) if value.path.is_ident("field") => {
+                    match value.lit {
+                        Lit::Str(ref name) => names.push((name.value(), name.span())),
+                        ref lit => return Err(Error::new_spanned(lit, "expected a string")),
+                    }
+                }
+                nested => return Err(Error::new_spanned(nested, "expected `field = \"...\"`")),
+            }
+        }
+    }
+    if names.is_empty() {
+        let ident = field.ident.as_ref().expect("fields must be named");
+        names.push((camel_case(&ident.to_string()), ident.span()));
+    }
+
+    let mut specs: Vec<FieldSpec> = Vec::new();
+    for (name, span) in names {
+        let spec = match fields::lookup(&name) {
+            Some(spec) => spec,
+            None => return Err(Error::new(span, format!("unknown paper field `{}`", name))),
+        };
+        if specs.first().map_or(false, |first| first.name != spec.name) {
+            let msg = "all fields of a struct field must share the same top level field";
+            return Err(Error::new(span, msg));
+        }
+        specs.push(spec);
+    }
+    Ok(specs)
+}
+
+/// Converts a snake case identifier, possibly raw, into camel case.
+fn camel_case(ident: &str) -> String {
+    let ident = ident.trim_start_matches("r#");
+    let mut camel = String::with_capacity(ident.len());
+    let mut upper = false;
+    for c in ident.chars() {
+        if c == '_' {
+            upper = !camel.is_empty();
+        } else if upper {
+            camel.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
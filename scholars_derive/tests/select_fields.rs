@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use scholars::v1::definition::{Author, BasePaper, FullPaper, Tldr};
+use scholars::v1::parameter::{
+    AuthorField, AuthorInfoField, BasePaperField, FullPaperField, PaperInfoField, SelectFields,
+};
+
+#[derive(Debug, SelectFields)]
+struct Paper {
+    title: Option<String>,
+    #[scholars(field = "authors.name")]
+    #[scholars(field = "authors.hIndex")]
+    authors: Vec<Author>,
+    tldr: Option<Tldr>,
+    #[scholars(field = "citations.citationCount")]
+    citations: Vec<BasePaper>,
+}
+
+#[derive(Debug, SelectFields)]
+struct SearchResult {
+    paper_id: Option<String>,
+    #[scholars(field = "citationCount")]
+    citations: Option<u64>,
+    r#abstract: Option<String>,
+}
+
+#[test]
+fn fields_match_struct_fields() {
+    let fields: HashSet<_> =
+        <Paper as SelectFields<FullPaperField>>::fields().into_iter().collect();
+    let expected = HashSet::from([
+        FullPaperField::from(PaperInfoField::Title),
+        FullPaperField::from(AuthorField::from(AuthorInfoField::Name)),
+        FullPaperField::from(AuthorField::HIndex),
+        FullPaperField::Tldr,
+        FullPaperField::citations_from(BasePaperField::CitationCount),
+    ]);
+    assert_eq!(fields, expected);
+
+    let fields = <SearchResult as SelectFields<BasePaperField>>::fields();
+    let expected = [
+        BasePaperField::from(PaperInfoField::PaperId),
+        BasePaperField::CitationCount,
+        BasePaperField::Abstract,
+    ];
+    assert_eq!(fields, expected);
+}
+
+#[test]
+fn struct_is_deserialized_from_api_fields() {
+    let json = r#"{
+        "paperId": "p1",
+        "title": "Title",
+        "authors": [{"authorId": "a1", "name": "Name", "hIndex": 3}],
+        "tldr": null,
+        "citations": [{"paperId": "p2", "citationCount": 7}]
+    }"#;
+    let paper: Paper = serde_json::from_str(json).unwrap();
+    assert_eq!(paper.title.as_deref(), Some("Title"));
+    assert_eq!(paper.authors[0].h_index, Some(3));
+    assert!(paper.tldr.is_none());
+    assert_eq!(paper.citations[0].citation_count, Some(7));
+
+    let result: SearchResult = serde_json::from_str(r#"{"paperId": "p1"}"#).unwrap();
+    assert_eq!(result.paper_id.as_deref(), Some("p1"));
+    assert_eq!(result.citations, None);
+    assert_eq!(result.r#abstract, None);
+}
+
+#[test]
+fn struct_is_converted_from_definitions() {
+    let mut paper = FullPaper::default();
+    paper.base.citation_count = Some(10);
+    let result = SearchResult::from(paper);
+    assert_eq!(result.citations, Some(10));
+
+    let mut paper = BasePaper::default();
+    paper.info.paper_id = Some("p1".to_owned());
+    assert_eq!(SearchResult::from(paper).paper_id.as_deref(), Some("p1"));
+}
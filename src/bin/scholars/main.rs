@@ -0,0 +1,194 @@
+//! A command-line tool to query the Semantic Scholar Academic Graph API.
+
+mod output;
+
+use std::error::Error;
+use std::io::{self, BufWriter};
+use std::str::FromStr;
+
+use clap::{Args, Parser, Subcommand};
+use reqwest::blocking::Client;
+use reqwest::header::{HeaderMap, HeaderValue};
+
+use scholars::v1::definition::{
+    AuthorWithPapers, BasePaper, Citation, FullPaper, PaperWithLinks, Reference,
+};
+use scholars::v1::endpoint::{
+    GetAuthor, GetAuthorPapers, GetPaper, GetPaperCitations, GetPaperReferences, GetPaperSearch,
+};
+use scholars::v1::pagination::{Page, Results};
+use scholars::v1::parameter::{
+    AuthorWithPapersField, BasePaperField, FullPaperField, PaperField, PaperWithLinksField,
+};
+use scholars::v1::query_params::{
+    AuthorPapersParams, AuthorParams, PaperCitationsParams, PaperParams, PaperReferencesParams,
+    PaperSearchParams,
+};
+
+use output::{Format, Output};
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+#[derive(Debug, Parser)]
+#[clap(name = "scholars", version, about)]
+struct Cli {
+    /// The API key, sent in the `x-api-key` header.
+    #[clap(long, env = "S2_API_KEY", hide_env_values = true)]
+    api_key: Option<String>,
+
+    /// The output format.
+    #[clap(long, short, value_enum, default_value_t = Format::Json)]
+    format: Format,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Looks up a paper by its ID.
+    Paper {
+        id: String,
+        #[clap(flatten)]
+        fields: FieldsArg,
+    },
+    /// Searches papers with a plain-text query.
+    Search {
+        query: String,
+        #[clap(flatten)]
+        fields: FieldsArg,
+        #[clap(flatten)]
+        page: PageArgs,
+    },
+    /// Looks up an author by its ID.
+    Author {
+        id: String,
+        #[clap(flatten)]
+        fields: FieldsArg,
+    },
+    /// Lists the papers of an author.
+    AuthorPapers {
+        id: String,
+        #[clap(flatten)]
+        fields: FieldsArg,
+        #[clap(flatten)]
+        page: PageArgs,
+    },
+    /// Lists the citations of a paper.
+    Citations {
+        id: String,
+        #[clap(flatten)]
+        fields: FieldsArg,
+        #[clap(flatten)]
+        page: PageArgs,
+    },
+    /// Lists the references of a paper.
+    References {
+        id: String,
+        #[clap(flatten)]
+        fields: FieldsArg,
+        #[clap(flatten)]
+        page: PageArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+struct FieldsArg {
+    /// Comma separated fields to request, like `title,authors.name`.
+    /// The API returns its default fields if not set.
+    #[clap(long, value_delimiter = ',')]
+    fields: Vec<String>,
+}
+
+impl FieldsArg {
+    fn parse<F: FromStr>(&self) -> Result<Option<Vec<F>>, F::Err> {
+        if self.fields.is_empty() {
+            return Ok(None);
+        }
+        self.fields.iter().map(|field| field.trim().parse()).collect::<Result<_, _>>().map(Some)
+    }
+}
+
+#[derive(Debug, Args)]
+struct PageArgs {
+    /// The maximum number of results.
+    #[clap(long, default_value_t = 100)]
+    limit: u64,
+
+    /// The number of results to skip.
+    #[clap(long, default_value_t = 0)]
+    offset: u64,
+}
+
+impl PageArgs {
+    // The page limit is the number of results per request, at most 100.
+    fn page(&self) -> CliResult<Page> {
+        Ok(Page::new(self.offset, self.limit.clamp(1, 100))?)
+    }
+
+    fn results(&self) -> Results {
+        Results::Limit(self.limit)
+    }
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn client(api_key: Option<&str>) -> CliResult<Client> {
+    let mut headers = HeaderMap::new();
+    if let Some(api_key) = api_key {
+        let mut value = HeaderValue::from_str(api_key)?;
+        value.set_sensitive(true);
+        headers.insert("x-api-key", value);
+    }
+    Ok(Client::builder().default_headers(headers).build()?)
+}
+
+fn run(cli: Cli) -> CliResult<()> {
+    let client = client(cli.api_key.as_deref())?;
+    let stdout = io::stdout();
+    let mut output = Output::new(cli.format, BufWriter::new(stdout.lock()));
+
+    match cli.command {
+        Command::Paper { id, fields } => {
+            let params = PaperParams::new(fields.parse::<FullPaperField>()?)?;
+            let paper: FullPaper = GetPaper::new(params, id).query(&client)?;
+            output.write_papers(&[paper])?;
+        }
+        Command::Search { query, fields, page } => {
+            let fields = fields.parse::<BasePaperField>()?;
+            let params = PaperSearchParams::new(query, fields, page.page()?)?;
+            let papers = GetPaperSearch::new(params).paged(page.results(), &client);
+            output.write_papers(&papers.collect::<Result<Vec<BasePaper>, _>>()?)?;
+        }
+        Command::Author { id, fields } => {
+            output.check_records()?;
+            let params = AuthorParams::new(fields.parse::<AuthorWithPapersField>()?)?;
+            let author: AuthorWithPapers = GetAuthor::new(params, id).query(&client)?;
+            output.write_records(&[author])?;
+        }
+        Command::AuthorPapers { id, fields, page } => {
+            let fields = fields.parse::<PaperWithLinksField>()?;
+            let params = AuthorPapersParams::new(fields, page.page()?)?;
+            let papers = GetAuthorPapers::new(params, id).paged(page.results(), &client);
+            output.write_papers(&papers.collect::<Result<Vec<PaperWithLinks>, _>>()?)?;
+        }
+        Command::Citations { id, fields, page } => {
+            let params = PaperCitationsParams::new(fields.parse::<PaperField>()?, page.page()?)?;
+            let citations = GetPaperCitations::new(params, id).paged(page.results(), &client);
+            let citations = citations.collect::<Result<Vec<Citation>, _>>()?;
+            output.write_links(&citations, |citation| citation.citing_paper.as_ref())?;
+        }
+        Command::References { id, fields, page } => {
+            let params = PaperReferencesParams::new(fields.parse::<PaperField>()?, page.page()?)?;
+            let references = GetPaperReferences::new(params, id).paged(page.results(), &client);
+            let references = references.collect::<Result<Vec<Reference>, _>>()?;
+            output.write_links(&references, |reference| reference.cited_paper.as_ref())?;
+        }
+    }
+    output.finish()
+}
@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+use scholars::v1::definition::BasePaper;
+use scholars::v1::export::{Bibliographic, BibliographyWriter, BibtexWriter};
+
+use crate::CliResult;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub(crate) enum Format {
+    /// A pretty printed JSON array.
+    Json,
+    /// One JSON object per line.
+    Jsonl,
+    /// One row per result, with a column per field.
+    Csv,
+    /// BibTeX entries, only for papers.
+    Bibtex,
+}
+
+/// Writes the results of a command in the selected [`Format`].
+pub(crate) struct Output<W> {
+    format: Format,
+    writer: W,
+}
+
+impl<W: Write> Output<W> {
+    pub(crate) fn new(format: Format, writer: W) -> Output<W> {
+        Output { format, writer }
+    }
+
+    /// Fails if the format can't be used for records other than papers.
+    pub(crate) fn check_records(&self) -> CliResult<()> {
+        match self.format {
+            Format::Bibtex => Err("BibTeX output is only available for papers".into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn write_records<T: Serialize>(&mut self, records: &[T]) -> CliResult<()> {
+        self.check_records()?;
+        match self.format {
+            Format::Json => {
+                serde_json::to_writer_pretty(&mut self.writer, records)?;
+                writeln!(self.writer)?;
+            }
+            Format::Jsonl => {
+                for record in records {
+                    serde_json::to_writer(&mut self.writer, record)?;
+                    writeln!(self.writer)?;
+                }
+            }
+            Format::Csv => write_csv(&mut self.writer, records)?,
+            Format::Bibtex => unreachable!("checked above"),
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_papers<P>(&mut self, papers: &[P]) -> CliResult<()>
+    where
+        P: Bibliographic + Serialize,
+    {
+        if self.format == Format::Bibtex {
+            BibtexWriter::new(&mut self.writer).write_papers(papers)?;
+            return Ok(());
+        }
+        self.write_records(papers)
+    }
+
+    /// Writes citations or references, exported to BibTeX by their linked `paper`.
+    pub(crate) fn write_links<T, F>(&mut self, links: &[T], paper: F) -> CliResult<()>
+    where
+        T: Serialize,
+        F: Fn(&T) -> Option<&BasePaper>,
+    {
+        if self.format == Format::Bibtex {
+            let papers = links.iter().filter_map(paper);
+            BibtexWriter::new(&mut self.writer).write_papers(papers)?;
+            return Ok(());
+        }
+        self.write_records(links)
+    }
+
+    pub(crate) fn finish(mut self) -> CliResult<()> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+/// Writes `records` as CSV, with a column per nested field named by its dotted
+/// path, like `citingPaper.title`. Lists are written as JSON.
+fn write_csv<W: Write, T: Serialize>(writer: &mut W, records: &[T]) -> CliResult<()> {
+    let mut rows = Vec::with_capacity(records.len());
+    for record in records {
+        let mut row = BTreeMap::new();
+        flatten("", serde_json::to_value(record)?, &mut row);
+        rows.push(row);
+    }
+    let columns: BTreeSet<&str> =
+        rows.iter().flat_map(|row| row.keys()).map(String::as_str).collect();
+    write_row(writer, columns.iter().copied())?;
+    for row in &rows {
+        let values = columns.iter().map(|column| row.get(*column).map_or("", String::as_str));
+        write_row(writer, values)?;
+    }
+    Ok(())
+}
+
+fn flatten(path: &str, value: Value, row: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(fields) => {
+            for (name, value) in fields {
+                let name = if path.is_empty() { name } else { format!("{}.{}", path, name) };
+                flatten(&name, value, row);
+            }
+        }
+        Value::Null => (),
+        Value::String(value) => {
+            row.insert(path.to_owned(), value);
+        }
+        value => {
+            row.insert(path.to_owned(), value.to_string());
+        }
+    }
+}
+
+fn write_row<'a, W: Write>(
+    writer: &mut W,
+    values: impl Iterator<Item = &'a str>,
+) -> io::Result<()> {
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        if value.contains(&[',', '"', '\n', '\r'][..]) {
+            write!(writer, "\"{}\"", value.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(value.as_bytes())?;
+        }
+    }
+    writer.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn csv_columns_are_flattened_and_quoted() {
+        let records = [
+            json!({"paperId": "p1", "title": "Hello, world", "citingPaper": {"year": 2020}}),
+            json!({"paperId": "p2", "title": "A \"quote\"", "authors": [{"name": "A"}]}),
+        ];
+        let mut csv = Vec::new();
+        write_csv(&mut csv, &records).unwrap();
+        let expected = "authors,citingPaper.year,paperId,title\n\
+            ,2020,p1,\"Hello, world\"\n\
+            \"[{\"\"name\"\":\"\"A\"\"}]\",,p2,\"A \"\"quote\"\"\"\n";
+        assert_eq!(String::from_utf8(csv).unwrap(), expected);
+    }
+}
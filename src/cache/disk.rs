@@ -0,0 +1,211 @@
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use super::{CacheStorage, CachedResponse};
+
+/// An on-disk [`CacheStorage`] that keeps one file per entry in a directory.
+///
+/// Each file starts with a line of JSON metadata followed by the raw response
+/// body. Expired entries are only removed when they are looked up, or by
+/// calling [`DiskStorage::purge`].
+#[derive(Clone, Debug)]
+pub struct DiskStorage {
+    dir: PathBuf,
+}
+
+// Distinguishes the temporary files of concurrent writes in the same process.
+static TMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+// Temporary files older than this are left over by interrupted writes.
+const TMP_GRACE: Duration = Duration::from_secs(60);
+
+/// The metadata line of an entry file.
+#[derive(Debug, Deserialize, Serialize)]
+struct Metadata {
+    key: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    // Seconds since the UNIX epoch.
+    expires_at: u64,
+}
+
+impl DiskStorage {
+    /// Uses `dir` as the cache directory, creating it if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<DiskStorage> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(DiskStorage { dir })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Removes every expired entry, and the temporary files left over by
+    /// interrupted writes. Temporary files modified in the last minute are
+    /// kept, since they may belong to a write in progress.
+    pub fn purge(&self) -> io::Result<()> {
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            match path.extension().and_then(|ext| ext.to_str()) {
+                Some("entry") => match Self::read(&path) {
+                    Ok((_, response)) if response.is_fresh() => (),
+                    _ => remove_file(&path)?,
+                },
+                Some("tmp") => {
+                    let modified = entry.metadata()?.modified()?;
+                    let age = SystemTime::now().duration_since(modified).unwrap_or_default();
+                    if age > TMP_GRACE {
+                        remove_file(&path)?;
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Entries are named after a stable (FNV-1a) hash of the key,
+    /// the full key is kept in the metadata to detect collisions.
+    fn path(&self, key: &str) -> PathBuf {
+        let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.dir.join(format!("{:016x}.entry", hash))
+    }
+
+    fn read(path: &Path) -> io::Result<(String, CachedResponse)> {
+        let mut reader = BufReader::new(fs::File::open(path)?);
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let metadata: Metadata = serde_json::from_str(&line)?;
+        let mut body = Vec::new();
+        reader.read_to_end(&mut body)?;
+
+        let invalid = |err| io::Error::new(io::ErrorKind::InvalidData, err);
+        let status = StatusCode::from_u16(metadata.status).map_err(invalid)?;
+        let headers = metadata
+            .headers
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let name = HeaderName::from_bytes(name.as_bytes()).ok()?;
+                Some((name, HeaderValue::from_str(&value).ok()?))
+            })
+            .collect();
+        let expires_at = UNIX_EPOCH + Duration::from_secs(metadata.expires_at);
+        let body = Bytes::from(body);
+        Ok((metadata.key, CachedResponse { status, headers, body, expires_at }))
+    }
+}
+
+fn remove_file(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+impl CacheStorage for DiskStorage {
+    fn get(&self, key: &str) -> io::Result<Option<CachedResponse>> {
+        match Self::read(&self.path(key)) {
+            Ok((stored, response)) if stored == key => Ok(Some(response)),
+            Ok(_) => Ok(None),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) -> io::Result<()> {
+        let headers = response.headers.iter();
+        let headers = headers
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+            .collect();
+        let expires_at = response.expires_at.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let metadata = Metadata {
+            key: key.to_owned(),
+            status: response.status.as_u16(),
+            headers,
+            expires_at: expires_at.as_secs(),
+        };
+
+        // Write to a temporary file first, so that concurrent readers
+        // never observe a partially written entry.
+        let path = self.path(key);
+        let count = TMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let tmp = path.with_extension(format!("{}.{}.tmp", std::process::id(), count));
+        let mut file = io::BufWriter::new(fs::File::create(&tmp)?);
+        serde_json::to_writer(&mut file, &metadata)?;
+        file.write_all(b"\n")?;
+        file.write_all(&response.body)?;
+        file.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+        fs::rename(&tmp, &path)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        remove_file(&self.path(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_round_trip() {
+        let dir = std::env::temp_dir().join(format!("scholars-cache-{}", std::process::id()));
+        let storage = DiskStorage::new(&dir).unwrap();
+        let response = CachedResponse {
+            status: StatusCode::OK,
+            headers: vec![(http::header::CONTENT_TYPE, HeaderValue::from_static("text/json"))],
+            body: Bytes::from_static(b"{\"a\":\n1}"),
+            // Already expired, so that it gets purged.
+            expires_at: UNIX_EPOCH + Duration::from_secs(60),
+        };
+
+        storage.put("key", response.clone()).unwrap();
+        assert_eq!(storage.get("key").unwrap(), Some(response));
+        assert_eq!(storage.get("other").unwrap(), None);
+
+        storage.purge().unwrap();
+        assert_eq!(storage.get("key").unwrap(), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn concurrent_writes_of_a_key_are_not_interleaved() {
+        let dir = std::env::temp_dir().join(format!("scholars-cache-race-{}", std::process::id()));
+        let storage = DiskStorage::new(&dir).unwrap();
+        let response = |byte: u8| CachedResponse {
+            status: StatusCode::OK,
+            headers: Vec::new(),
+            body: Bytes::from(vec![byte; 1 << 16]),
+            expires_at: SystemTime::now() + Duration::from_secs(60),
+        };
+
+        let threads: Vec<_> = (b'a'..=b'h')
+            .map(|byte| {
+                let storage = storage.clone();
+                std::thread::spawn(move || storage.put("key", response(byte)))
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap().unwrap();
+        }
+
+        let body = storage.get("key").unwrap().unwrap().body;
+        assert_eq!(body.len(), 1 << 16);
+        assert!(body.iter().all(|byte| *byte == body[0]));
+        let files = fs::read_dir(&dir).unwrap().count();
+        assert_eq!(files, 1, "temporary files are renamed into place");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::sync::Mutex;
+
+use super::{CacheStorage, CachedResponse};
+
+/// An in-memory [`CacheStorage`] that evicts the least recently used
+/// entry once it holds `capacity` entries.
+#[derive(Debug)]
+pub struct MemoryStorage {
+    capacity: usize,
+    inner: Mutex<Lru>,
+}
+
+#[derive(Debug, Default)]
+struct Lru {
+    // Entries mapped to their last access tick.
+    entries: HashMap<String, (CachedResponse, u64)>,
+    // Access ticks mapped to their entry, the first one is the least recently used.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Lru {
+    fn touch(&mut self, key: &str) -> Option<&CachedResponse> {
+        let tick = self.tick;
+        let (response, last) = self.entries.get_mut(key)?;
+        self.order.remove(last);
+        self.order.insert(tick, key.to_owned());
+        *last = tick;
+        self.tick += 1;
+        Some(response)
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some((_, last)) = self.entries.remove(key) {
+            self.order.remove(&last);
+        }
+    }
+}
+
+impl MemoryStorage {
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> MemoryStorage {
+        assert!(capacity > 0, "cache capacity must be greater than 0");
+        MemoryStorage { capacity, inner: Mutex::new(Lru::default()) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        *self.lock() = Lru::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru> {
+        // The LRU is left consistent after every operation, so it is safe to keep
+        // using it even if a thread panicked while holding the lock.
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for MemoryStorage {
+    fn default() -> Self {
+        MemoryStorage::new(1_024)
+    }
+}
+
+impl CacheStorage for MemoryStorage {
+    fn get(&self, key: &str) -> io::Result<Option<CachedResponse>> {
+        Ok(self.lock().touch(key).cloned())
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) -> io::Result<()> {
+        let mut lru = self.lock();
+        lru.remove(key);
+        while lru.entries.len() >= self.capacity {
+            let oldest = lru.order.keys().next().copied();
+            match oldest.and_then(|tick| lru.order.remove(&tick)) {
+                Some(evicted) => lru.entries.remove(&evicted),
+                None => break,
+            };
+        }
+        let tick = lru.tick;
+        lru.tick += 1;
+        lru.order.insert(tick, key.to_owned());
+        lru.entries.insert(key.to_owned(), (response, tick));
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        self.lock().remove(key);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use bytes::Bytes;
+    use http::StatusCode;
+
+    use super::*;
+
+    fn response() -> CachedResponse {
+        let (status, headers, body) = (StatusCode::OK, vec![], Bytes::new());
+        CachedResponse { status, headers, body, expires_at: SystemTime::now() }
+    }
+
+    #[test]
+    fn least_recently_used_entry_is_evicted() {
+        let storage = MemoryStorage::new(2);
+        storage.put("a", response()).unwrap();
+        storage.put("b", response()).unwrap();
+        storage.get("a").unwrap();
+        storage.put("c", response()).unwrap();
+
+        assert_eq!(storage.len(), 2);
+        assert!(storage.get("a").unwrap().is_some());
+        assert!(storage.get("b").unwrap().is_none());
+        assert!(storage.get("c").unwrap().is_some());
+    }
+}
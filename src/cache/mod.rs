@@ -0,0 +1,412 @@
+mod disk;
+pub use disk::*;
+
+mod memory;
+pub use memory::*;
+
+use std::io;
+use std::time::{Duration, SystemTime};
+
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue, AGE, CACHE_CONTROL, DATE, EXPIRES};
+use http::{request::Builder, Method, Response, StatusCode};
+use url::{ParseError, Url};
+
+use crate::client::BaseClient;
+
+/// A response stored by a [`CacheStorage`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CachedResponse {
+    pub status: StatusCode,
+    pub headers: Vec<(HeaderName, HeaderValue)>,
+    pub body: Bytes,
+    /// The point in time after which this response must not be used anymore.
+    pub expires_at: SystemTime,
+}
+
+impl CachedResponse {
+    fn from_response(response: &Response<Bytes>, expires_at: SystemTime) -> Self {
+        let headers = response.headers().iter();
+        let headers = headers.map(|(name, value)| (name.clone(), value.clone())).collect();
+        let (status, body) = (response.status(), response.body().clone());
+        CachedResponse { status, headers, body, expires_at }
+    }
+
+    fn to_response(&self) -> Response<Bytes> {
+        let mut response = Response::new(self.body.clone());
+        *response.status_mut() = self.status;
+        response.headers_mut().extend(self.headers.iter().cloned().map(|(n, v)| (Some(n), v)));
+        response
+    }
+
+    pub fn is_fresh(&self) -> bool {
+        self.expires_at > SystemTime::now()
+    }
+}
+
+/// A storage backend for cached responses, keyed by the normalized request URL.
+///
+/// Methods take `&self` so that a storage can be shared by async
+/// clients, implementations must handle their own synchronization.
+pub trait CacheStorage {
+    fn get(&self, key: &str) -> io::Result<Option<CachedResponse>>;
+
+    fn put(&self, key: &str, response: CachedResponse) -> io::Result<()>;
+
+    fn remove(&self, key: &str) -> io::Result<()>;
+}
+
+/// How long responses are kept in the cache.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CachePolicy {
+    default_ttl: Duration,
+    endpoint_ttls: Vec<(Vec<String>, Duration)>,
+    honor_headers: bool,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        CachePolicy::new(Duration::from_secs(60 * 60))
+    }
+}
+
+impl CachePolicy {
+    /// A policy that keeps every successful response for `default_ttl`.
+    pub fn new(default_ttl: Duration) -> CachePolicy {
+        CachePolicy { default_ttl, endpoint_ttls: Vec::new(), honor_headers: true }
+    }
+
+    /// Overrides the time to live of the responses for the endpoints whose URL path
+    /// ends with `pattern`, where a `*` segment matches any segment. For example,
+    /// `paper/*/citations` matches the paper citations endpoint for any paper ID.
+    /// Patterns are tried in the order they were added, the first match wins.
+    pub fn with_endpoint_ttl(mut self, pattern: &str, ttl: Duration) -> CachePolicy {
+        let segments = pattern.trim_matches('/').split('/').map(str::to_owned).collect();
+        self.endpoint_ttls.push((segments, ttl));
+        self
+    }
+
+    /// Whether the HTTP caching headers of responses are honored, enabled by default.
+    /// If so, `no-store` and `no-cache` responses are not cached, `max-age`, or else
+    /// `Expires`, takes precedence over the configured time to live, and the `Age`
+    /// of the response is subtracted from it.
+    pub fn with_cache_headers(mut self, honor: bool) -> CachePolicy {
+        self.honor_headers = honor;
+        self
+    }
+
+    fn endpoint_ttl(&self, url: &Url) -> Duration {
+        let path: Vec<_> = url.path_segments().map(Iterator::collect).unwrap_or_default();
+        let matches = |pattern: &[String]| {
+            pattern.len() <= path.len()
+                && pattern
+                    .iter()
+                    .rev()
+                    .zip(path.iter().rev())
+                    .all(|(pattern, segment)| pattern == "*" || pattern == segment)
+        };
+        let ttls = self.endpoint_ttls.iter();
+        let ttl = ttls.filter(|(pattern, _)| matches(pattern)).map(|(_, ttl)| *ttl).next();
+        ttl.unwrap_or(self.default_ttl)
+    }
+
+    /// The time to live of `response`, or `None` if it must not be cached.
+    fn ttl(&self, url: &Url, response: &Response<Bytes>) -> Option<Duration> {
+        if !response.status().is_success() {
+            return None;
+        }
+        let mut ttl = self.endpoint_ttl(url);
+        if self.honor_headers {
+            let headers = response.headers();
+            let mut max_age = None;
+            let directives = headers.get_all(CACHE_CONTROL).iter();
+            let directives = directives.filter_map(|value| value.to_str().ok());
+            for directive in directives.flat_map(|value| value.split(',')).map(str::trim) {
+                let directive = directive.to_ascii_lowercase();
+                if directive == "no-store" || directive == "no-cache" {
+                    return None;
+                }
+                if let Some(value) = directive.strip_prefix("max-age=") {
+                    // A malformed `max-age` is ignored, as if it was absent.
+                    max_age =
+                        value.trim_matches('"').parse().ok().map(Duration::from_secs).or(max_age);
+                }
+            }
+            if let Some(max_age) = max_age {
+                ttl = max_age;
+            } else if let Some(expires) = headers.get(EXPIRES) {
+                // An invalid date, such as `0`, means the response is already expired.
+                let expires = http_date(expires)?;
+                // The lifetime is relative to the `Date` of the origin server, to avoid clock skews.
+                let date = headers.get(DATE).and_then(http_date).unwrap_or_else(SystemTime::now);
+                ttl = expires.duration_since(date).unwrap_or(Duration::ZERO);
+            }
+            let age = headers.get(AGE).and_then(|value| value.to_str().ok()?.trim().parse().ok());
+            ttl = ttl.saturating_sub(Duration::from_secs(age.unwrap_or(0)));
+        }
+        if ttl == Duration::ZERO {
+            None
+        } else {
+            Some(ttl)
+        }
+    }
+}
+
+fn http_date(value: &HeaderValue) -> Option<SystemTime> {
+    httpdate::parse_http_date(value.to_str().ok()?).ok()
+}
+
+/// Normalizes a request URL into a cache key.
+///
+/// The order of the `fields` parameter values is not stable across
+/// executions, so they are sorted to get the same key for the same request.
+pub fn cache_key(url: &Url) -> String {
+    let mut key = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            let value = if name == "fields" {
+                let mut fields: Vec<_> = value.split(',').collect();
+                fields.sort_unstable();
+                fields.join(",")
+            } else {
+                value.into_owned()
+            };
+            (name.into_owned(), value)
+        })
+        .collect();
+    if !pairs.is_empty() {
+        key.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    key.set_fragment(None);
+    key.into()
+}
+
+/// A client wrapper that serves repeated `GET` requests from a [`CacheStorage`].
+///
+/// Only successful responses are cached. Storage failures are
+/// logged and the request is then sent through the inner client.
+#[derive(Clone, Debug)]
+pub struct CachedClient<C, S = MemoryStorage> {
+    client: C,
+    storage: S,
+    policy: CachePolicy,
+}
+
+impl<C, S: CacheStorage> CachedClient<C, S> {
+    pub fn new(client: C, storage: S, policy: CachePolicy) -> CachedClient<C, S> {
+        CachedClient { client, storage, policy }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// The request URL if the request is cacheable.
+    fn cacheable(request: &Builder) -> Option<Url> {
+        if request.method_ref() != Some(&Method::GET) {
+            return None;
+        }
+        Url::parse(&request.uri_ref()?.to_string()).ok()
+    }
+
+    fn lookup(&self, key: &str) -> Option<Response<Bytes>> {
+        match self.storage.get(key) {
+            Ok(Some(cached)) if cached.is_fresh() => {
+                log::debug!("serving {} from cache", key);
+                return Some(cached.to_response());
+            }
+            Ok(Some(_)) => {
+                if let Err(err) = self.storage.remove(key) {
+                    log::warn!("failed to evict stale cache entry: {}", err);
+                }
+            }
+            Ok(None) => (),
+            Err(err) => log::warn!("failed to read cache entry: {}", err),
+        }
+        None
+    }
+
+    fn store(&self, url: &Url, key: &str, response: &Response<Bytes>) {
+        if let Some(ttl) = self.policy.ttl(url, response) {
+            let cached = CachedResponse::from_response(response, SystemTime::now() + ttl);
+            if let Err(err) = self.storage.put(key, cached) {
+                log::warn!("failed to write cache entry: {}", err);
+            }
+        }
+    }
+}
+
+impl<C: BaseClient, S> BaseClient for CachedClient<C, S> {
+    type Error = C::Error;
+
+    fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError> {
+        self.client.endpoint(endpoint)
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use super::*;
+    use crate::client::Client;
+
+    impl<C: Client, S: CacheStorage> Client for CachedClient<C, S> {
+        fn send(&self, request: Builder, body: Vec<u8>) -> Result<Response<Bytes>, C::Error> {
+            let url = match Self::cacheable(&request) {
+                Some(url) => url,
+                None => return self.client.send(request, body),
+            };
+            let key = cache_key(&url);
+            if let Some(response) = self.lookup(&key) {
+                return Ok(response);
+            }
+            let response = self.client.send(request, body)?;
+            self.store(&url, &key, &response);
+            Ok(response)
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+    use super::*;
+    use crate::client::AsyncClient;
+
+    #[async_trait::async_trait]
+    impl<C, S> AsyncClient for CachedClient<C, S>
+    where
+        C: AsyncClient + Sync,
+        S: CacheStorage + Sync,
+    {
+        async fn send(&self, request: Builder, body: Vec<u8>) -> Result<Response<Bytes>, C::Error> {
+            let url = match Self::cacheable(&request) {
+                Some(url) => url,
+                None => return self.client.send(request, body).await,
+            };
+            let key = cache_key(&url);
+            if let Some(response) = self.lookup(&key) {
+                return Ok(response);
+            }
+            let response = self.client.send(request, body).await?;
+            self.store(&url, &key, &response);
+            Ok(response)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    fn response(cache_control: Option<&str>) -> Response<Bytes> {
+        let headers: Vec<_> =
+            cache_control.map(|value| (CACHE_CONTROL, value)).into_iter().collect();
+        with_headers(&headers)
+    }
+
+    fn with_headers(headers: &[(HeaderName, &str)]) -> Response<Bytes> {
+        let mut response = Response::new(Bytes::from_static(b"{}"));
+        for (name, value) in headers {
+            response.headers_mut().append(name, HeaderValue::from_str(value).unwrap());
+        }
+        response
+    }
+
+    #[test]
+    fn cache_key_sorts_fields() {
+        let a = cache_key(&url("https://a.org/paper/1?fields=title,year&limit=2"));
+        let b = cache_key(&url("https://a.org/paper/1?fields=year,title&limit=2"));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn endpoint_ttl_matches_wildcard_segments() {
+        let hour = Duration::from_secs(3600);
+        let policy = CachePolicy::new(hour)
+            .with_endpoint_ttl("paper/*/citations", Duration::from_secs(60))
+            .with_endpoint_ttl("paper/*", Duration::from_secs(120));
+
+        let citations = url("https://a.org/graph/v1/paper/1/citations");
+        let paper = url("https://a.org/graph/v1/paper/1");
+        let author = url("https://a.org/graph/v1/author/1");
+        assert_eq!(policy.endpoint_ttl(&citations), Duration::from_secs(60));
+        assert_eq!(policy.endpoint_ttl(&paper), Duration::from_secs(120));
+        assert_eq!(policy.endpoint_ttl(&author), hour);
+    }
+
+    #[test]
+    fn cache_control_header_overrides_ttl() {
+        let policy = CachePolicy::new(Duration::from_secs(3600));
+        let url = url("https://a.org/paper/1");
+
+        let max_age = response(Some("public, max-age=10"));
+        assert_eq!(policy.ttl(&url, &max_age), Some(Duration::from_secs(10)));
+        assert_eq!(policy.ttl(&url, &response(Some("no-store"))), None);
+        assert_eq!(policy.ttl(&url, &response(None)), Some(Duration::from_secs(3600)));
+
+        let ignored = policy.with_cache_headers(false);
+        assert_eq!(ignored.ttl(&url, &max_age), Some(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn malformed_max_age_falls_back_to_ttl() {
+        let policy = CachePolicy::new(Duration::from_secs(3600));
+        let url = url("https://a.org/paper/1");
+
+        let malformed = response(Some("max-age=soon"));
+        assert_eq!(policy.ttl(&url, &malformed), Some(Duration::from_secs(3600)));
+        let expires = with_headers(&[
+            (CACHE_CONTROL, "max-age=-1"),
+            (DATE, "Sun, 18 Oct 2026 10:00:00 GMT"),
+            (EXPIRES, "Sun, 18 Oct 2026 10:01:00 GMT"),
+        ]);
+        assert_eq!(policy.ttl(&url, &expires), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn expires_header_overrides_ttl() {
+        let policy = CachePolicy::new(Duration::from_secs(3600));
+        let url = url("https://a.org/paper/1");
+
+        let expires = with_headers(&[
+            (DATE, "Sun, 18 Oct 2026 10:00:00 GMT"),
+            (EXPIRES, "Sun, 18 Oct 2026 10:01:00 GMT"),
+        ]);
+        assert_eq!(policy.ttl(&url, &expires), Some(Duration::from_secs(60)));
+        let expired = with_headers(&[
+            (DATE, "Sun, 18 Oct 2026 10:00:00 GMT"),
+            (EXPIRES, "Sun, 18 Oct 2026 09:00:00 GMT"),
+        ]);
+        assert_eq!(policy.ttl(&url, &expired), None);
+        assert_eq!(policy.ttl(&url, &with_headers(&[(EXPIRES, "0")])), None);
+
+        let max_age = with_headers(&[
+            (CACHE_CONTROL, "max-age=10"),
+            (EXPIRES, "Sun, 18 Oct 2026 09:00:00 GMT"),
+        ]);
+        assert_eq!(policy.ttl(&url, &max_age), Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn age_header_is_subtracted_from_ttl() {
+        let policy = CachePolicy::new(Duration::from_secs(3600));
+        let url = url("https://a.org/paper/1");
+
+        let max_age = with_headers(&[(CACHE_CONTROL, "max-age=100"), (AGE, "40")]);
+        assert_eq!(policy.ttl(&url, &max_age), Some(Duration::from_secs(60)));
+        let stale = with_headers(&[(CACHE_CONTROL, "max-age=100"), (AGE, "100")]);
+        assert_eq!(policy.ttl(&url, &stale), None);
+        let fallback = with_headers(&[(AGE, "600")]);
+        assert_eq!(policy.ttl(&url, &fallback), Some(Duration::from_secs(3000)));
+        let malformed = with_headers(&[(AGE, "old")]);
+        assert_eq!(policy.ttl(&url, &malformed), Some(Duration::from_secs(3600)));
+    }
+}
@@ -1,4 +1,5 @@
 use std::error::Error;
+use std::time::Duration;
 
 use bytes::Bytes;
 use http::{request::Builder, Response};
@@ -12,6 +13,16 @@ pub trait BaseClient {
     /// Get the URL for the endpoint for the client.
     fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError>;
 }
+
+/// The timeout of a request, set in its extensions when the endpoint has a timeout
+/// or a deadline. Clients should fail the request once it elapses, and their errors
+/// should convert into [`ApiError::Timeout`](crate::error::ApiError::Timeout).
+///
+/// It is applied by the `reqwest`, `ureq` and `hyper` clients. Other clients
+/// may ignore it, in which case only the deadlines are checked between requests.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RequestTimeout(pub Duration);
+
 /// A trait representing a client which can communicate with a Semantic Scholar API endpoint.
 #[cfg(feature = "blocking")]
 pub trait Client: BaseClient {
@@ -1,4 +1,5 @@
 use std::error::Error;
+use std::time::{Duration, Instant};
 
 use bytes::Bytes;
 use http::{request::Builder, Method, Request, Response, Uri};
@@ -6,7 +7,8 @@ use serde::de::DeserializeOwned;
 use serde_urlencoded::ser::Error as UrlEncodedError;
 use url::Url;
 
-use crate::{client::BaseClient, error::ApiError, urlencoded::UrlEncodedQuery};
+use crate::client::{BaseClient, RequestTimeout};
+use crate::{error::ApiError, meter::Meter, trace::Trace, urlencoded::UrlEncodedQuery};
 
 /// A trait for providing the necessary information for a single REST API endpoint.
 pub(crate) trait Endpoint {
@@ -19,13 +21,26 @@ pub(crate) trait Endpoint {
     /// The path to the endpoint.
     fn endpoint(&self) -> &str;
 
+    /// The endpoint name, like `paper_citations`, used for diagnostics.
+    fn name(&self) -> &'static str;
+
+    /// The ID of the paper or author the endpoint is queried for, if any.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    fn id(&self) -> Option<&str>;
+
+    /// The timeout of every request sent to the endpoint.
+    fn timeout(&self) -> Option<Duration>;
+
+    /// The instant after which no more requests are sent to the endpoint.
+    fn deadline(&self) -> Option<Instant>;
+
     /// URL query string for the endpoint.
     fn query_params(&self) -> Result<UrlEncodedQuery<'_>, UrlEncodedError>;
 }
 
-pub(in crate) type EndpointError<E, C> = ApiError<<E as Endpoint>::Error, <C as BaseClient>::Error>;
+pub(crate) type EndpointError<E, C> = ApiError<<E as Endpoint>::Error, <C as BaseClient>::Error>;
 
-pub(in crate) type EndpointResult<T, E, C> = Result<T, EndpointError<E, C>>;
+pub(crate) type EndpointResult<T, E, C> = Result<T, EndpointError<E, C>>;
 
 /// Converts [`url::Url`] into [`http::Uri`].
 #[inline]
@@ -33,20 +48,43 @@ fn url_to_http_uri(url: &Url) -> Uri {
     url.as_str().parse().expect("a parsed `Url` must be a valid `Uri`")
 }
 
+/// The timeout of the next request to the endpoint, shortened to the time left
+/// before its deadline. Fails if the deadline has already been reached.
+fn request_timeout<E: Endpoint, C: BaseClient>(
+    endpoint: &E,
+) -> Result<Option<Duration>, EndpointError<E, C>> {
+    let left = match endpoint.deadline() {
+        Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+            Some(left) if !left.is_zero() => Some(left),
+            _ => return Err(ApiError::Timeout),
+        },
+        None => None,
+    };
+    Ok(match (endpoint.timeout(), left) {
+        (Some(timeout), Some(left)) => Some(timeout.min(left)),
+        (timeout, left) => timeout.or(left),
+    })
+}
+
 /// Gets the Endpoint's [`Url`] and creates the request [`Builder`].
 #[inline]
 fn build_request<E: Endpoint, C: BaseClient>(
     endpoint: &E,
     client: &C,
 ) -> Result<(Builder, Url), EndpointError<E, C>> {
+    let timeout = request_timeout::<E, C>(endpoint)?;
     let mut url = client.endpoint(endpoint.endpoint())?;
     endpoint.query_params()?.set_url(&mut url);
-    log::debug!("querying Semantic Scholar API at {}", url.as_str());
-    let builder = Request::builder().method(endpoint.method()).uri(url_to_http_uri(&url));
+    log::debug!("querying Semantic Scholar API {} endpoint at {}", endpoint.name(), url.as_str());
+    let mut builder = Request::builder().method(endpoint.method()).uri(url_to_http_uri(&url));
+    if let Some(timeout) = timeout {
+        builder = builder.extension(RequestTimeout(timeout));
+    }
     Ok((builder, url))
 }
 
-/// Serializes the JSON payload.
+/// Deserializes the JSON payload, directly from the response body, into `T`
+/// or, if the response status is not successful, into the endpoint error.
 #[inline]
 fn serialize_response<T, E: Endpoint, C: BaseClient>(
     rsp: Response<Bytes>,
@@ -57,13 +95,20 @@ where
     E::Error: DeserializeOwned,
     EndpointError<E, C>: From<C::Error>,
 {
-    let value = serde_json::from_slice(rsp.body())?;
     let status = rsp.status();
     if !status.is_success() {
-        let err = serde_json::from_value::<E::Error>(value)?;
+        let err = serde_json::from_slice::<E::Error>(rsp.body())?;
         return Err(ApiError::from_response(err, status, url));
     }
-    serde_json::from_value::<T>(value).map_err(ApiError::from_data_type::<T>)
+    serde_json::from_slice::<T>(rsp.body()).map_err(|err| {
+        // Invalid JSON is reported as such, and valid JSON
+        // that doesn't match `T` as a data type error.
+        if err.is_data() {
+            ApiError::from_data_type::<T>(err)
+        } else {
+            ApiError::from_json(err)
+        }
+    })
 }
 
 #[cfg(feature = "blocking")]
@@ -78,9 +123,16 @@ mod blocking {
         EndpointError<E, C>: From<C::Error>,
     {
         fn query(&self, client: &C) -> EndpointResult<T, E, C> {
-            let (req, url) = build_request(self, client)?;
-            let rsp = client.send(req, vec![])?;
-            serialize_response::<T, E, C>(rsp, url)
+            let trace = Trace::query(self);
+            trace.in_scope(|| {
+                let (req, url) = build_request(self, client)?;
+                let meter = Meter::start(self);
+                let rsp = client.send(req, vec![]);
+                meter.record(&rsp);
+                let rsp = rsp?;
+                trace.record_response(&rsp);
+                serialize_response::<T, E, C>(rsp, url)
+            })
         }
     }
 }
@@ -98,9 +150,17 @@ mod r#async {
         EndpointError<E, C>: From<C::Error>,
     {
         async fn query_async(&self, client: &C) -> EndpointResult<T, E, C> {
-            let (req, url) = build_request(self, client)?;
-            let rsp = client.send(req, vec![]).await?;
-            serialize_response::<T, E, C>(rsp, url)
+            let trace = Trace::query(self);
+            let query = async {
+                let (req, url) = build_request(self, client)?;
+                let meter = Meter::start(self);
+                let rsp = client.send(req, vec![]).await;
+                meter.record(&rsp);
+                let rsp = rsp?;
+                trace.record_response(&rsp);
+                serialize_response::<T, E, C>(rsp, url)
+            };
+            trace.instrument(query).await
         }
     }
 }
@@ -52,6 +52,11 @@ pub enum ApiError<E: Error, C: Error> {
         /// see [`std::any::type_name`] for more information.
         typename: &'static str,
     },
+
+    /// The request timed out, or the deadline of the query was reached.
+    /// Paged iterators and crawls stop after returning it for a deadline.
+    #[error("the request timed out or its deadline was reached")]
+    Timeout,
 }
 
 impl<C: Error, E: Error> ApiError<E, C> {
@@ -0,0 +1,237 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue};
+use http::{request::Builder, Method, Response, Uri};
+use url::{ParseError, Url};
+
+use crate::client::BaseClient;
+
+/// The method and URI of a request sent by a [`HookedClient`], see [`Hook::on_response`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SentRequest {
+    pub method: Method,
+    pub uri: Uri,
+    /// The time elapsed between sending the request and receiving its response.
+    pub elapsed: Duration,
+}
+
+/// A hook called on the requests sent and responses received by a [`HookedClient`].
+///
+/// Both methods do nothing by default, so hooks only implement the ones they need.
+pub trait Hook: Send + Sync {
+    /// Called before a request is sent. Headers can be added
+    /// or changed through [`Builder::headers_mut`].
+    fn on_request(&self, request: &mut Builder) {
+        let _ = request;
+    }
+
+    /// Called after a response is received. It is not called if the client failed
+    /// to send the request, but it is for responses with an error status code.
+    fn on_response(&self, request: &SentRequest, response: &mut Response<Bytes>) {
+        let _ = (request, response);
+    }
+}
+
+/// A [`Hook`] that sets a header on every request, like a `User-Agent` with an app name.
+#[derive(Clone, Debug)]
+pub struct SetHeader {
+    name: HeaderName,
+    value: HeaderValue,
+}
+
+impl SetHeader {
+    pub fn new(name: HeaderName, value: HeaderValue) -> SetHeader {
+        SetHeader { name, value }
+    }
+}
+
+impl Hook for SetHeader {
+    fn on_request(&self, request: &mut Builder) {
+        if let Some(headers) = request.headers_mut() {
+            headers.insert(self.name.clone(), self.value.clone());
+        }
+    }
+}
+
+struct RequestFn<F>(F);
+
+impl<F: Fn(&mut Builder) + Send + Sync> Hook for RequestFn<F> {
+    fn on_request(&self, request: &mut Builder) {
+        (self.0)(request)
+    }
+}
+
+struct ResponseFn<F>(F);
+
+impl<F: Fn(&SentRequest, &mut Response<Bytes>) + Send + Sync> Hook for ResponseFn<F> {
+    fn on_response(&self, request: &SentRequest, response: &mut Response<Bytes>) {
+        (self.0)(request, response)
+    }
+}
+
+/// A client wrapper that runs a chain of [`Hook`]s around every request.
+///
+/// Request hooks run in the order they were added, and response hooks in the
+/// reverse order, so the first hook added is the outermost one of the chain.
+#[derive(Clone)]
+pub struct HookedClient<C> {
+    client: C,
+    hooks: Vec<Arc<dyn Hook>>,
+}
+
+impl<C: Debug> Debug for HookedClient<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let hooks = self.hooks.len();
+        f.debug_struct("HookedClient").field("client", &self.client).field("hooks", &hooks).finish()
+    }
+}
+
+impl<C> HookedClient<C> {
+    pub fn new(client: C) -> HookedClient<C> {
+        HookedClient { client, hooks: Vec::new() }
+    }
+
+    /// Adds `hook` at the end of the chain.
+    pub fn with_hook(mut self, hook: impl Hook + 'static) -> HookedClient<C> {
+        self.hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Adds a hook that only runs `f` on requests.
+    pub fn on_request<F>(self, f: F) -> HookedClient<C>
+    where
+        F: Fn(&mut Builder) + Send + Sync + 'static,
+    {
+        self.with_hook(RequestFn(f))
+    }
+
+    /// Adds a hook that only runs `f` on responses.
+    pub fn on_response<F>(self, f: F) -> HookedClient<C>
+    where
+        F: Fn(&SentRequest, &mut Response<Bytes>) + Send + Sync + 'static,
+    {
+        self.with_hook(ResponseFn(f))
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Runs the request hooks, returning what is needed to run the response hooks.
+    fn before(&self, request: &mut Builder) -> (Method, Uri, Instant) {
+        for hook in &self.hooks {
+            hook.on_request(request);
+        }
+        let method = request.method_ref().cloned().unwrap_or_default();
+        let uri = request.uri_ref().cloned().unwrap_or_default();
+        (method, uri, Instant::now())
+    }
+
+    fn after(&self, (method, uri, sent): (Method, Uri, Instant), response: &mut Response<Bytes>) {
+        let request = SentRequest { method, uri, elapsed: sent.elapsed() };
+        for hook in self.hooks.iter().rev() {
+            hook.on_response(&request, response);
+        }
+    }
+}
+
+impl<C: BaseClient> BaseClient for HookedClient<C> {
+    type Error = C::Error;
+
+    fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError> {
+        self.client.endpoint(endpoint)
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use super::*;
+    use crate::client::Client;
+
+    impl<C: Client> Client for HookedClient<C> {
+        fn send(&self, mut request: Builder, body: Vec<u8>) -> Result<Response<Bytes>, C::Error> {
+            let sent = self.before(&mut request);
+            let mut response = self.client.send(request, body)?;
+            self.after(sent, &mut response);
+            Ok(response)
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+    use super::*;
+    use crate::client::AsyncClient;
+
+    #[async_trait::async_trait]
+    impl<C: AsyncClient + Sync> AsyncClient for HookedClient<C> {
+        async fn send(
+            &self,
+            mut request: Builder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, C::Error> {
+            let sent = self.before(&mut request);
+            let mut response = self.client.send(request, body).await?;
+            self.after(sent, &mut response);
+            Ok(response)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use http::header::USER_AGENT;
+    use http::Request;
+
+    use super::*;
+    use crate::client::Client;
+
+    /// A client that echoes the request headers in the response.
+    struct Echo;
+
+    impl BaseClient for Echo {
+        type Error = http::Error;
+
+        fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError> {
+            Url::parse(endpoint)
+        }
+    }
+
+    impl Client for Echo {
+        fn send(&self, request: Builder, _: Vec<u8>) -> Result<Response<Bytes>, http::Error> {
+            let (parts, _) = request.body(())?.into_parts();
+            let mut response = Response::new(Bytes::new());
+            *response.headers_mut() = parts.headers;
+            Ok(response)
+        }
+    }
+
+    #[test]
+    fn hooks_run_in_chain_order() {
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let (first, second) = (order.clone(), order.clone());
+        let client = HookedClient::new(Echo)
+            .with_hook(SetHeader::new(USER_AGENT, HeaderValue::from_static("app/1.0")))
+            .on_response(move |_, _| first.lock().unwrap().push("first"))
+            .on_response(move |request, response| {
+                assert_eq!(request.uri.path(), "/paper/1");
+                response.headers_mut().remove(USER_AGENT);
+                second.lock().unwrap().push("second");
+            });
+
+        let request = Request::builder().uri("https://a.org/paper/1");
+        let response = client.send(request, Vec::new()).unwrap();
+        assert_eq!(response.headers().get(USER_AGENT), None);
+        assert_eq!(*order.lock().unwrap(), ["second", "first"]);
+
+        let client = HookedClient::new(Echo)
+            .with_hook(SetHeader::new(USER_AGENT, HeaderValue::from_static("app/1.0")));
+        let response = client.send(Request::builder(), Vec::new()).unwrap();
+        assert_eq!(response.headers()[USER_AGENT], "app/1.0");
+    }
+}
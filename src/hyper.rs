@@ -0,0 +1,79 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use hyper::client::connect::Connect;
+use hyper::Body;
+
+use crate::client::{AsyncClient, BaseClient, RequestTimeout};
+use crate::error::ApiError;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ClientError {
+    #[error("`hyper` error: {source}")]
+    Hyper {
+        #[from]
+        source: hyper::Error,
+    },
+    #[error("`http` error: {source}")]
+    Http {
+        #[from]
+        source: http::Error,
+    },
+    #[error("request timed out: {source}")]
+    Timeout {
+        #[from]
+        source: tokio::time::error::Elapsed,
+    },
+}
+
+impl<E: Error> From<ClientError> for ApiError<E, ClientError> {
+    fn from(error: ClientError) -> Self {
+        match error {
+            ClientError::Hyper { .. } => Self::from_client(error),
+            ClientError::Http { source } => Self::from_http(source),
+            ClientError::Timeout { .. } => Self::Timeout,
+        }
+    }
+}
+
+/// Implements [`crate::client::BaseClient`] and [`crate::client::AsyncClient`]
+/// for [`hyper::Client`], with any connector. The API is only served over
+/// HTTPS, so the connector must support TLS, like the one of `hyper-tls`.
+///
+/// A [`RequestTimeout`] is applied with [`tokio::time::timeout`],
+/// so the Tokio runtime must have its time driver enabled.
+impl<C> BaseClient for hyper::Client<C, Body>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    type Error = ClientError;
+
+    fn endpoint(&self, endpoint: &str) -> Result<url::Url, url::ParseError> {
+        url::Url::parse(endpoint)
+    }
+}
+
+#[async_trait]
+impl<C> AsyncClient for hyper::Client<C, Body>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    async fn send(
+        &self,
+        builder: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<http::Response<Bytes>, Self::Error> {
+        let request = builder.body(Body::from(body))?;
+        let timeout = request.extensions().get::<RequestTimeout>().map(|timeout| timeout.0);
+        let response = async {
+            let (parts, body) = self.request(request).await?.into_parts();
+            Ok(http::Response::from_parts(parts, hyper::body::to_bytes(body).await?))
+        };
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, response).await?,
+            None => response.await,
+        }
+    }
+}
@@ -17,9 +17,33 @@ pub mod client;
 #[cfg(any(feature = "blocking", feature = "async"))]
 pub(crate) mod endpoint;
 #[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) mod meter;
+#[cfg(any(feature = "blocking", feature = "async"))]
 pub(crate) mod query;
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) mod trace;
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub mod cache;
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub mod hooks;
 
 #[cfg(any(feature = "reqwest-async", feature = "reqwest-blocking"))]
 pub mod reqwest;
 
+#[cfg(feature = "ureq-blocking")]
+pub mod ureq;
+
+#[cfg(feature = "hyper-async")]
+pub mod hyper;
+
+#[cfg(feature = "tower-async")]
+pub mod tower;
+
 pub mod error;
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
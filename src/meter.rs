@@ -0,0 +1,193 @@
+//! Metrics of the queries, exported through the [`metrics`] facade when the
+//! `metrics` feature is enabled. Without it, a [`Meter`] does nothing.
+//!
+//! Every metric is labeled with the `endpoint` name, like `paper_citations`:
+//!
+//! - `scholars_requests_total`: counter of the requests sent, also labeled with the
+//!   `status` class of the response (`2xx`, `4xx`, ...) or `error` if the client failed.
+//! - `scholars_request_duration_seconds`: histogram of the request latencies.
+//! - `scholars_response_bytes_total`: counter of the response body bytes received.
+//! - `scholars_rate_limited_total`: counter of the `429 Too Many Requests` responses.
+//!
+//! Retries are not counted, since this crate doesn't retry failed requests.
+//! They can be counted by the client, like a `tower` retry layer under a `TowerClient`.
+//!
+//! [`metrics`]: https://docs.rs/metrics
+
+#[cfg(feature = "metrics")]
+use std::time::Instant;
+
+use bytes::Bytes;
+use http::Response;
+
+use crate::endpoint::Endpoint;
+
+#[cfg(feature = "metrics")]
+const REQUESTS: &str = "scholars_requests_total";
+#[cfg(feature = "metrics")]
+const DURATION: &str = "scholars_request_duration_seconds";
+#[cfg(feature = "metrics")]
+const BYTES: &str = "scholars_response_bytes_total";
+#[cfg(feature = "metrics")]
+const RATE_LIMITED: &str = "scholars_rate_limited_total";
+
+/// Registers the units and descriptions of the metrics, once.
+#[cfg(feature = "metrics")]
+fn describe() {
+    use metrics::{describe_counter, describe_histogram, Unit};
+    use std::sync::Once;
+
+    static DESCRIBE: Once = Once::new();
+    DESCRIBE.call_once(|| {
+        describe_counter!(REQUESTS, "Semantic Scholar API requests sent.");
+        describe_histogram!(DURATION, Unit::Seconds, "Semantic Scholar API request latencies.");
+        describe_counter!(BYTES, Unit::Bytes, "Semantic Scholar API response bytes received.");
+        describe_counter!(RATE_LIMITED, "Semantic Scholar API requests rate limited.");
+    });
+}
+
+pub(crate) struct Meter {
+    #[cfg(feature = "metrics")]
+    endpoint: &'static str,
+    #[cfg(feature = "metrics")]
+    start: Instant,
+}
+
+impl Meter {
+    /// Starts measuring a request to `endpoint`.
+    #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+    pub(crate) fn start<E: Endpoint>(endpoint: &E) -> Meter {
+        #[cfg(feature = "metrics")]
+        {
+            describe();
+            Meter { endpoint: endpoint.name(), start: Instant::now() }
+        }
+        #[cfg(not(feature = "metrics"))]
+        {
+            Meter {}
+        }
+    }
+
+    /// Records the outcome of the request, either a response or a client error.
+    #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+    pub(crate) fn record<E>(self, result: &Result<Response<Bytes>, E>) {
+        #[cfg(feature = "metrics")]
+        {
+            let endpoint = self.endpoint;
+            let status = match result {
+                Ok(rsp) => match rsp.status().as_u16() / 100 {
+                    1 => "1xx",
+                    2 => "2xx",
+                    3 => "3xx",
+                    4 => "4xx",
+                    _ => "5xx",
+                },
+                Err(_) => "error",
+            };
+            metrics::increment_counter!(REQUESTS, "endpoint" => endpoint, "status" => status);
+            metrics::histogram!(DURATION, self.start.elapsed(), "endpoint" => endpoint);
+            if let Ok(rsp) = result {
+                let bytes = rsp.body().len() as u64;
+                metrics::counter!(BYTES, bytes, "endpoint" => endpoint);
+                if rsp.status() == http::StatusCode::TOO_MANY_REQUESTS {
+                    metrics::increment_counter!(RATE_LIMITED, "endpoint" => endpoint);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "metrics", feature = "blocking", feature = "v1"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use http::request::Builder;
+    use http::StatusCode;
+    use metrics::{
+        Counter, CounterFn, Gauge, Histogram, Key, KeyName, Recorder, SharedString, Unit,
+    };
+    use url::{ParseError, Url};
+
+    use super::*;
+    use crate::client::{BaseClient, Client};
+    use crate::v1::definition::AuthorWithPapers;
+    use crate::v1::endpoint::GetAuthor;
+    use crate::v1::parameter::{AuthorField, AuthorWithPapersField};
+    use crate::v1::query_params::AuthorParams;
+
+    type Counters = Arc<Mutex<HashMap<String, Arc<AtomicU64>>>>;
+
+    struct Count(Arc<AtomicU64>);
+
+    impl CounterFn for Count {
+        fn increment(&self, value: u64) {
+            self.0.fetch_add(value, Ordering::Relaxed);
+        }
+
+        fn absolute(&self, value: u64) {
+            self.0.store(value, Ordering::Relaxed);
+        }
+    }
+
+    /// A recorder that keeps the counters, by their name and labels.
+    struct Counting(Counters);
+
+    impl Recorder for Counting {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key) -> Counter {
+            let mut counters = self.0.lock().unwrap();
+            let count = counters.entry(key.to_string()).or_default().clone();
+            Counter::from_arc(Arc::new(Count(count)))
+        }
+
+        fn register_gauge(&self, _: &Key) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, _: &Key) -> Histogram {
+            Histogram::noop()
+        }
+    }
+
+    struct Stub;
+
+    impl BaseClient for Stub {
+        type Error = http::Error;
+
+        fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError> {
+            Url::parse(endpoint)
+        }
+    }
+
+    impl Client for Stub {
+        fn send(&self, _: Builder, _: Vec<u8>) -> Result<Response<Bytes>, http::Error> {
+            let body = Bytes::from_static(br#"{"error": "Too Many Requests"}"#);
+            Response::builder().status(StatusCode::TOO_MANY_REQUESTS).body(body)
+        }
+    }
+
+    #[test]
+    fn rate_limited_requests_are_counted() {
+        let counters = Counters::default();
+        metrics::set_boxed_recorder(Box::new(Counting(counters.clone()))).unwrap();
+
+        let params =
+            AuthorParams::new(Some([AuthorWithPapersField::Author(AuthorField::Url)])).unwrap();
+        let author: Result<AuthorWithPapers, _> =
+            GetAuthor::new(params, "a1".to_owned()).query(&Stub);
+        assert!(author.is_err());
+
+        let counters = counters.lock().unwrap();
+        let count = |key: &str| counters[key].load(Ordering::Relaxed);
+        assert_eq!(count("Key(scholars_requests_total, [endpoint = author, status = 4xx])"), 1);
+        assert_eq!(count("Key(scholars_rate_limited_total, [endpoint = author])"), 1);
+        assert_eq!(count("Key(scholars_response_bytes_total, [endpoint = author])"), 30);
+    }
+}
@@ -1,8 +1,10 @@
 use std::error::Error;
+use std::time::Duration;
 
 use bytes::Bytes;
 use http::{header::HeaderName, HeaderValue};
 
+use crate::client::RequestTimeout;
 use crate::error::ApiError;
 
 #[derive(Debug, thiserror::Error)]
@@ -23,6 +25,7 @@ pub enum ClientError {
 impl<E: Error> From<ClientError> for ApiError<E, ClientError> {
     fn from(error: ClientError) -> Self {
         match error {
+            ClientError::Reqwest { ref source } if source.is_timeout() => Self::Timeout,
             ClientError::Reqwest { .. } => Self::from_client(error),
             ClientError::Http { source } => Self::from_http(source),
         }
@@ -46,18 +49,21 @@ macro_rules! base_client_impl {
     };
 }
 
-/// Helper function that tries to convert a [`http::Request`] into some type `T`.
+/// Helper function that tries to convert a [`http::Request`] into some type `T`,
+/// returning it with its [`RequestTimeout`], which is lost in the conversion.
 /// This is used to convert [`http::Request`] into either async or blocking [`reqwest`] Request.
 #[inline]
 fn convert_from_http_request<T, R>(
     builder: http::request::Builder,
     body: T,
-) -> Result<R, ClientError>
+) -> Result<(R, Option<Duration>), ClientError>
 where
     R: TryFrom<http::Request<T>>,
     ClientError: From<<R as TryFrom<http::Request<T>>>::Error>,
 {
-    Ok(R::try_from(builder.body(body)?)?)
+    let request = builder.body(body)?;
+    let timeout = request.extensions().get::<RequestTimeout>().map(|timeout| timeout.0);
+    Ok((R::try_from(request)?, timeout))
 }
 
 /// Helper function that constructs a [`http::Response`] from head parts.
@@ -92,7 +98,10 @@ mod blocking {
             builder: http::request::Builder,
             body: Vec<u8>,
         ) -> Result<http::Response<Bytes>, Self::Error> {
-            let rsp = self.execute(convert_from_http_request(builder, body)?)?;
+            let (mut req, timeout): (reqwest::blocking::Request, _) =
+                convert_from_http_request(builder, body)?;
+            *req.timeout_mut() = timeout;
+            let rsp = self.execute(req)?;
             let http_rsp = convert_to_http_response(rsp.headers(), rsp.status(), rsp.version());
             Ok(http_rsp.body(rsp.bytes()?)?)
         }
@@ -116,7 +125,10 @@ mod r#async {
             builder: http::request::Builder,
             body: Vec<u8>,
         ) -> Result<http::Response<Bytes>, Self::Error> {
-            let rsp = self.execute(convert_from_http_request(builder, body)?).await?;
+            let (mut req, timeout): (reqwest::Request, _) =
+                convert_from_http_request(builder, body)?;
+            *req.timeout_mut() = timeout;
+            let rsp = self.execute(req).await?;
             let http_rsp = convert_to_http_response(rsp.headers(), rsp.status(), rsp.version());
             Ok(http_rsp.body(rsp.bytes().await?)?)
         }
@@ -0,0 +1,124 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{Request, Response};
+use tower::{BoxError, Service, ServiceExt};
+
+use crate::client::{AsyncClient, BaseClient};
+use crate::error::ApiError;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ClientError {
+    #[error("service error: {source}")]
+    Service { source: BoxError },
+    #[error("`http` error: {source}")]
+    Http {
+        #[from]
+        source: http::Error,
+    },
+}
+
+impl<E: Error> From<ClientError> for ApiError<E, ClientError> {
+    fn from(error: ClientError) -> Self {
+        match error {
+            ClientError::Service { .. } => Self::from_client(error),
+            ClientError::Http { source } => Self::from_http(source),
+        }
+    }
+}
+
+/// An [`AsyncClient`] that sends requests through a [`tower::Service`],
+/// so that tower layers, like timeouts, rate limits or retries,
+/// can be composed under the endpoint queries.
+///
+/// The service is cloned for every request, since [`Service::call`] takes
+/// `&mut self`, so it should be cheap to clone. Services that are not
+/// can be wrapped in a `tower::buffer::Buffer`.
+///
+/// A [`RequestTimeout`](crate::client::RequestTimeout) is left in the request
+/// extensions for the service to apply, and its errors are not converted into
+/// [`ApiError::Timeout`].
+///
+/// # Example
+///
+/// ```ignore
+/// let service = tower::ServiceBuilder::new()
+///     .concurrency_limit(4)
+///     .timeout(Duration::from_secs(10))
+///     .service(http_service);
+/// let client = TowerClient::new(service);
+/// let paper: FullPaper = endpoint.query_async(&client).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct TowerClient<S> {
+    service: S,
+}
+
+impl<S> TowerClient<S> {
+    pub fn new(service: S) -> TowerClient<S> {
+        TowerClient { service }
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.service
+    }
+
+    pub fn into_inner(self) -> S {
+        self.service
+    }
+}
+
+/// Implements [`crate::client::BaseClient`] and
+/// [`crate::client::AsyncClient`] for [`TowerClient`].
+impl<S> BaseClient for TowerClient<S> {
+    type Error = ClientError;
+
+    fn endpoint(&self, endpoint: &str) -> Result<url::Url, url::ParseError> {
+        url::Url::parse(endpoint)
+    }
+}
+
+#[async_trait]
+impl<S> AsyncClient for TowerClient<S>
+where
+    S: Service<Request<Vec<u8>>, Response = Response<Bytes>> + Clone + Send + Sync,
+    S::Future: Send,
+    S::Error: Into<BoxError>,
+{
+    async fn send(
+        &self,
+        builder: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, Self::Error> {
+        let service = self.service.clone();
+        let rsp = service.oneshot(builder.body(body)?).await;
+        rsp.map_err(|err| ClientError::Service { source: err.into() })
+    }
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use crate::v1::definition::FullPaper;
+    use crate::v1::endpoint::GetPaper;
+    use crate::v1::parameter::PaperInfoField;
+    use crate::v1::query_params::PaperParams;
+
+    #[tokio::test]
+    async fn queries_are_sent_through_the_service() {
+        let service = tower::service_fn(|request: Request<Vec<u8>>| async move {
+            assert_eq!(request.uri().path(), "/graph/v1/paper/p1");
+            let body = Bytes::from_static(br#"{"paperId": "p1", "title": "Tower"}"#);
+            Ok::<_, Infallible>(Response::new(body))
+        });
+        let client = TowerClient::new(service);
+        let params = PaperParams::new(Some([PaperInfoField::Title])).unwrap();
+        let paper: FullPaper =
+            GetPaper::new(params, "p1".to_owned()).query_async(&client).await.unwrap();
+        assert_eq!(paper.base.info.title.as_deref(), Some("Tower"));
+    }
+}
@@ -0,0 +1,225 @@
+//! Tracing spans of the queries and of the page fetches of the paged iterators,
+//! enabled by the `tracing` feature. Without it, a [`Trace`] does nothing.
+//!
+//! Retries are not recorded, since this crate doesn't retry failed requests.
+//! They can be traced by the client, like a `tower` retry layer under a `TowerClient`.
+
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+use bytes::Bytes;
+use http::Response;
+
+use crate::endpoint::Endpoint;
+
+#[cfg(feature = "tracing")]
+use tracing::field::Empty;
+
+pub(crate) struct Trace {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    start: Instant,
+}
+
+impl Trace {
+    #[cfg(feature = "tracing")]
+    fn new(span: tracing::Span) -> Trace {
+        Trace { span, start: Instant::now() }
+    }
+
+    /// The span of a single query, with the endpoint name and the paper or author ID.
+    /// The response status, body size and the query duration are recorded later.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn query<E: Endpoint>(endpoint: &E) -> Trace {
+        #[cfg(feature = "tracing")]
+        {
+            Trace::new(tracing::info_span!(
+                "query",
+                endpoint = endpoint.name(),
+                id = endpoint.id(),
+                status = Empty,
+                size = Empty,
+                duration_ms = Empty,
+            ))
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            Trace {}
+        }
+    }
+
+    /// The span of a page fetch, with the endpoint name, the paper or author ID
+    /// and the page offset and limit. The number of results and the fetch
+    /// duration are recorded later. The span of its query is nested in it.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn page<E: Endpoint>(endpoint: &E, offset: u64, limit: u64) -> Trace {
+        #[cfg(feature = "tracing")]
+        {
+            Trace::new(tracing::info_span!(
+                "page",
+                endpoint = endpoint.name(),
+                id = endpoint.id(),
+                offset,
+                limit,
+                results = Empty,
+                duration_ms = Empty,
+            ))
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            Trace {}
+        }
+    }
+
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn record_response(&self, response: &Response<Bytes>) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("status", response.status().as_u16());
+            self.span.record("size", response.body().len());
+        }
+    }
+
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn record_results(&self, results: usize) {
+        #[cfg(feature = "tracing")]
+        self.span.record("results", results);
+    }
+
+    /// Runs `f` inside the span.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn in_scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.in_scope(f)
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            f()
+        }
+    }
+
+    /// Polls `future` inside the span.
+    #[cfg(feature = "async")]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Instrument::instrument(future, self.span.clone())
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            future
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for Trace {
+    fn drop(&mut self) {
+        self.span.record("duration_ms", self.start.elapsed().as_millis() as u64);
+    }
+}
+
+#[cfg(all(test, feature = "tracing", feature = "blocking", feature = "v1"))]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+
+    use http::request::Builder;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+    use url::{ParseError, Url};
+
+    use super::*;
+    use crate::client::{BaseClient, Client};
+    use crate::v1::definition::Citation;
+    use crate::v1::endpoint::GetPaperCitations;
+    use crate::v1::pagination::{Page, Results};
+    use crate::v1::parameter::PaperField;
+    use crate::v1::query_params::PaperCitationsParams;
+
+    #[derive(Default)]
+    struct Fields(BTreeMap<String, String>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.insert(field.name().to_owned(), format!("{:?}", value));
+        }
+    }
+
+    type Spans = Arc<Mutex<Vec<(&'static str, Fields)>>>;
+
+    /// A subscriber that keeps the name and fields of every span.
+    struct Recorder(Spans);
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields::default();
+            span.record(&mut fields);
+            let mut spans = self.0.lock().unwrap();
+            spans.push((span.metadata().name(), fields));
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.0.lock().unwrap();
+            values.record(&mut spans[span.into_u64() as usize - 1].1);
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    struct Stub;
+
+    impl BaseClient for Stub {
+        type Error = http::Error;
+
+        fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError> {
+            Url::parse(endpoint)
+        }
+    }
+
+    impl Client for Stub {
+        fn send(&self, _: Builder, _: Vec<u8>) -> Result<Response<Bytes>, http::Error> {
+            let body = br#"{"offset": 0, "data": [{"citingPaper": {"paperId": "p2"}}]}"#;
+            Ok(Response::new(Bytes::from_static(body)))
+        }
+    }
+
+    #[test]
+    fn pages_and_queries_are_traced() {
+        let spans = Spans::default();
+        let params = PaperCitationsParams::new(Some([PaperField::Contexts]), Page::default());
+        let endpoint = GetPaperCitations::new(params.unwrap(), "p1".to_owned());
+        tracing::subscriber::with_default(Recorder(spans.clone()), || {
+            let citations = endpoint.paged(Results::All, &Stub);
+            assert_eq!(citations.collect::<Result<Vec<Citation>, _>>().unwrap().len(), 1);
+        });
+
+        let spans = spans.lock().unwrap();
+        let names: Vec<_> = spans.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["page", "query"]);
+        let (page, query) = (&spans[0].1 .0, &spans[1].1 .0);
+        assert_eq!(page["endpoint"], "\"paper_citations\"");
+        assert_eq!(page["offset"], "0");
+        assert_eq!(page["results"], "1");
+        assert_eq!(query["id"], "\"p1\"");
+        assert_eq!(query["status"], "200");
+        assert!(query.contains_key("duration_ms"));
+    }
+}
@@ -0,0 +1,120 @@
+use std::error::Error;
+use std::io::Read;
+
+use bytes::Bytes;
+use http::header::ToStrError;
+
+use crate::client::{BaseClient, Client, RequestTimeout};
+use crate::error::ApiError;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ClientError {
+    #[error("`ureq` error: {source}")]
+    Ureq {
+        #[from]
+        source: Box<ureq::Transport>,
+    },
+    #[error("`http` error: {source}")]
+    Http {
+        #[from]
+        source: http::Error,
+    },
+    #[error("non visible ASCII header value: {source}")]
+    Header {
+        #[from]
+        source: ToStrError,
+    },
+    #[error("failed to read the response body: {source}")]
+    Io {
+        #[from]
+        source: std::io::Error,
+    },
+}
+
+impl<E: Error> From<ClientError> for ApiError<E, ClientError> {
+    fn from(error: ClientError) -> Self {
+        match error {
+            ClientError::Http { source } => Self::from_http(source),
+            _ if error.is_timeout() => Self::Timeout,
+            _ => Self::from_client(error),
+        }
+    }
+}
+
+impl ClientError {
+    /// Whether the request timed out, while connecting or reading the response.
+    fn is_timeout(&self) -> bool {
+        let io = match self {
+            ClientError::Ureq { source } => Error::source(&**source).and_then(|e| e.downcast_ref()),
+            ClientError::Io { source } => Some(source),
+            _ => None,
+        };
+        io.map_or(false, |io: &std::io::Error| {
+            matches!(io.kind(), std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock)
+        })
+    }
+}
+
+/// Helper function that converts a [`http::Request`] into a [`ureq::Request`].
+#[inline]
+fn convert_from_http_request(
+    agent: &ureq::Agent,
+    builder: http::request::Builder,
+) -> Result<ureq::Request, ClientError> {
+    let request = builder.body(())?;
+    let mut ureq_request = agent.request(request.method().as_str(), &request.uri().to_string());
+    if let Some(RequestTimeout(timeout)) = request.extensions().get() {
+        ureq_request = ureq_request.timeout(*timeout);
+    }
+    for (name, value) in request.headers() {
+        ureq_request = ureq_request.set(name.as_str(), value.to_str()?);
+    }
+    Ok(ureq_request)
+}
+
+/// Helper function that converts a [`ureq::Response`] into a [`http::Response`].
+#[inline]
+fn convert_to_http_response(rsp: ureq::Response) -> Result<http::Response<Bytes>, ClientError> {
+    let version = match rsp.http_version() {
+        "HTTP/0.9" => http::Version::HTTP_09,
+        "HTTP/1.0" => http::Version::HTTP_10,
+        "HTTP/2.0" => http::Version::HTTP_2,
+        _ => http::Version::HTTP_11,
+    };
+    let mut http_rsp = http::Response::builder().status(rsp.status()).version(version);
+    for name in rsp.headers_names() {
+        for value in rsp.all(&name) {
+            http_rsp = http_rsp.header(name.as_str(), value);
+        }
+    }
+    let mut body = Vec::new();
+    rsp.into_reader().read_to_end(&mut body)?;
+    Ok(http_rsp.body(Bytes::from(body))?)
+}
+
+/// Implements [`crate::client::BaseClient`] and
+/// [`crate::client::Client`] for [`ureq::Agent`].
+impl BaseClient for ureq::Agent {
+    type Error = ClientError;
+
+    fn endpoint(&self, endpoint: &str) -> Result<url::Url, url::ParseError> {
+        url::Url::parse(endpoint)
+    }
+}
+
+impl Client for ureq::Agent {
+    fn send(
+        &self,
+        builder: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<http::Response<Bytes>, Self::Error> {
+        // `ureq` returns error status codes as errors, but their
+        // responses are kept to deserialize the API error object.
+        let rsp = match convert_from_http_request(self, builder)?.send_bytes(&body) {
+            Ok(rsp) | Err(ureq::Error::Status(_, rsp)) => rsp,
+            Err(ureq::Error::Transport(transport)) => return Err(Box::new(transport).into()),
+        };
+        convert_to_http_response(rsp)
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::ops::Bound;
+
+use crate::v1::definition::PaperWithLinks;
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub use network::CoauthorNetwork;
+
+/// An author of the network, keyed by its Semantic Scholar ID.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct AuthorNode {
+    name: Option<String>,
+    papers: u64,
+    // Co-author IDs mapped to the number of shared papers.
+    coauthors: BTreeMap<String, u64>,
+}
+
+/// An author listed in a paper without a Semantic Scholar ID.
+///
+/// These can't be told apart across papers, so they are
+/// kept aside instead of being added as network nodes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnidentifiedAuthor {
+    /// ID of the paper where the author was listed.
+    pub paper_id: Option<String>,
+    /// The author's name, if provided.
+    pub name: Option<String>,
+}
+
+/// An undirected co-authorship network weighted by the number of shared papers.
+///
+/// Papers are deduplicated by their Semantic Scholar ID, so the same
+/// paper listed by multiple seed authors is only accounted for once.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CoauthorGraph {
+    authors: BTreeMap<String, AuthorNode>,
+    papers: HashSet<String>,
+    unidentified: Vec<UnidentifiedAuthor>,
+}
+
+impl CoauthorGraph {
+    pub fn new() -> CoauthorGraph {
+        CoauthorGraph::default()
+    }
+
+    /// Adds all authors of `paper` and links every pair of them.
+    pub fn add_paper(&mut self, paper: &PaperWithLinks) {
+        let paper_id = paper.base.info.paper_id.as_ref();
+        if let Some(id) = paper_id {
+            if !self.papers.insert(id.clone()) {
+                return;
+            }
+        }
+
+        let mut ids = BTreeSet::new();
+        for author in &paper.authors {
+            match author.author_id {
+                Some(ref id) => {
+                    let node = self.authors.entry(id.clone()).or_default();
+                    if node.name.is_none() {
+                        node.name = author.name.clone();
+                    }
+                    if ids.insert(id) {
+                        node.papers += 1;
+                    }
+                }
+                None => self.unidentified.push(UnidentifiedAuthor {
+                    paper_id: paper_id.cloned(),
+                    name: author.name.clone(),
+                }),
+            }
+        }
+
+        for a in &ids {
+            for b in &ids {
+                if a != b {
+                    let node = self.authors.get_mut(*a).expect("author was just inserted");
+                    *node.coauthors.entry((*b).clone()).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    /// Identified authors and their names, ordered by ID.
+    pub fn authors(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.authors.iter().map(|(id, node)| (id.as_str(), node.name.as_deref()))
+    }
+
+    /// Authors listed without an ID, in insertion order.
+    pub fn unidentified(&self) -> &[UnidentifiedAuthor] {
+        &self.unidentified
+    }
+
+    /// Number of papers added to the network for `author_id`.
+    pub fn paper_count(&self, author_id: &str) -> u64 {
+        self.authors.get(author_id).map_or(0, |node| node.papers)
+    }
+
+    /// Number of distinct co-authors of `author_id`.
+    pub fn degree(&self, author_id: &str) -> usize {
+        self.authors.get(author_id).map_or(0, |node| node.coauthors.len())
+    }
+
+    /// Number of papers co-authored by `a` and `b`.
+    pub fn shared_papers(&self, a: &str, b: &str) -> u64 {
+        let node = self.authors.get(a);
+        node.and_then(|node| node.coauthors.get(b)).copied().unwrap_or(0)
+    }
+
+    /// Co-authors of `author_id` and the number of shared papers, ordered by ID.
+    pub fn coauthors<'a>(&'a self, author_id: &str) -> impl Iterator<Item = (&'a str, u64)> {
+        let coauthors = self.authors.get(author_id).map(|node| &node.coauthors);
+        coauthors.into_iter().flatten().map(|(id, shared)| (id.as_str(), *shared))
+    }
+
+    /// Every co-authorship link, listed once as `(a, b, shared papers)` with `a < b`.
+    pub fn edges(&self) -> impl Iterator<Item = (&str, &str, u64)> {
+        self.authors.iter().flat_map(|(a, node)| {
+            let coauthors =
+                node.coauthors.range::<String, _>((Bound::Excluded(a), Bound::Unbounded));
+            coauthors.map(move |(b, shared)| (a.as_str(), b.as_str(), *shared))
+        })
+    }
+
+    /// Groups of authors connected through co-authorship,
+    /// ordered from the largest to the smallest group.
+    pub fn connected_components(&self) -> Vec<Vec<&str>> {
+        let mut visited = HashSet::new();
+        let mut components = Vec::new();
+
+        for start in self.authors.keys() {
+            if !visited.insert(start.as_str()) {
+                continue;
+            }
+            let mut component = Vec::new();
+            let mut stack = vec![start.as_str()];
+            while let Some(id) = stack.pop() {
+                component.push(id);
+                for (coauthor, _) in self.coauthors(id) {
+                    if visited.insert(coauthor) {
+                        stack.push(coauthor);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        components
+    }
+}
+
+impl<'a> Extend<&'a PaperWithLinks> for CoauthorGraph {
+    fn extend<I: IntoIterator<Item = &'a PaperWithLinks>>(&mut self, papers: I) {
+        for paper in papers {
+            self.add_paper(paper);
+        }
+    }
+}
+
+impl Extend<PaperWithLinks> for CoauthorGraph {
+    fn extend<I: IntoIterator<Item = PaperWithLinks>>(&mut self, papers: I) {
+        for paper in papers {
+            self.add_paper(&paper);
+        }
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+mod network {
+    use super::*;
+    use crate::client::BaseClient;
+    use crate::error::ApiError;
+    use crate::v1::error::ResponseError;
+    use crate::v1::pagination::{Page, Results};
+    use crate::v1::parameter::{PaperInfoField, PaperWithLinksField};
+    use crate::v1::query_params::AuthorPapersParams;
+
+    type CoauthorError<C> = ApiError<ResponseError, <C as BaseClient>::Error>;
+
+    /// Builds a [`CoauthorGraph`] by paging through the papers of a set of seed authors.
+    #[derive(Debug)]
+    pub struct CoauthorNetwork {
+        seeds: Vec<String>,
+        results: Results,
+    }
+
+    impl CoauthorNetwork {
+        /// `results` limits the number of papers fetched for each seed author.
+        pub fn new(seeds: impl IntoIterator<Item = String>, results: Results) -> CoauthorNetwork {
+            CoauthorNetwork { seeds: seeds.into_iter().collect(), results }
+        }
+
+        fn query_params() -> AuthorPapersParams {
+            let fields = [
+                PaperWithLinksField::from(PaperInfoField::PaperId),
+                PaperWithLinksField::Authors(None),
+            ];
+            AuthorPapersParams::new(Some(fields), Page::default())
+                .expect("paper ID and authors fields must be valid")
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    mod blocking {
+        use super::*;
+        use crate::client::Client;
+        use crate::v1::endpoint::GetAuthorPapers;
+
+        impl CoauthorNetwork {
+            /// Stops at the first error returned by the API.
+            pub fn build<C>(self, client: &C) -> Result<CoauthorGraph, CoauthorError<C>>
+            where
+                C: Client,
+                CoauthorError<C>: From<C::Error>,
+            {
+                let mut graph = CoauthorGraph::new();
+                for author_id in self.seeds {
+                    let endpoint = GetAuthorPapers::new(Self::query_params(), author_id);
+                    for paper in endpoint.paged::<PaperWithLinks, C>(self.results, client) {
+                        graph.add_paper(&paper?);
+                    }
+                }
+                Ok(graph)
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    mod r#async {
+        use super::*;
+        use crate::client::AsyncClient;
+        use crate::v1::endpoint::iter::Next;
+        use crate::v1::endpoint::GetAuthorPapers;
+
+        impl CoauthorNetwork {
+            /// Stops at the first error returned by the API.
+            pub async fn build_async<C>(self, client: &C) -> Result<CoauthorGraph, CoauthorError<C>>
+            where
+                C: AsyncClient + Sync,
+                CoauthorError<C>: From<C::Error>,
+            {
+                let mut graph = CoauthorGraph::new();
+                for author_id in self.seeds {
+                    let endpoint = GetAuthorPapers::new(Self::query_params(), author_id);
+                    let mut papers =
+                        endpoint.paged_async::<PaperWithLinks, C>(self.results, client);
+                    while let Some(paper) = Next(&mut papers).await {
+                        graph.add_paper(&paper?);
+                    }
+                }
+                Ok(graph)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::AuthorInfo;
+
+    fn paper(id: &str, authors: &[Option<&str>]) -> PaperWithLinks {
+        let mut paper = PaperWithLinks::default();
+        paper.base.info.paper_id = Some(id.to_owned());
+        paper.authors = authors
+            .iter()
+            .map(|id| AuthorInfo { author_id: id.map(str::to_owned), name: None })
+            .collect();
+        paper
+    }
+
+    #[test]
+    fn shared_papers_are_counted_once_per_paper() {
+        let mut graph = CoauthorGraph::new();
+        graph.add_paper(&paper("p1", &[Some("a"), Some("b")]));
+        graph.add_paper(&paper("p2", &[Some("a"), Some("b"), Some("c")]));
+        // Same paper returned for another seed author.
+        graph.add_paper(&paper("p2", &[Some("a"), Some("b"), Some("c")]));
+
+        assert_eq!(graph.shared_papers("a", "b"), 2);
+        assert_eq!(graph.shared_papers("b", "c"), 1);
+        assert_eq!(graph.degree("a"), 2);
+        assert_eq!(graph.paper_count("a"), 2);
+        assert_eq!(graph.edges().count(), 3);
+    }
+
+    #[test]
+    fn unidentified_authors_are_not_nodes() {
+        let mut graph = CoauthorGraph::new();
+        graph.add_paper(&paper("p1", &[Some("a"), None]));
+
+        assert_eq!(graph.degree("a"), 0);
+        assert_eq!(graph.unidentified().len(), 1);
+        assert_eq!(graph.unidentified()[0].paper_id.as_deref(), Some("p1"));
+    }
+
+    #[test]
+    fn connected_components_are_ordered_by_size() {
+        let mut graph = CoauthorGraph::new();
+        graph.add_paper(&paper("p1", &[Some("d"), Some("e")]));
+        graph.add_paper(&paper("p2", &[Some("a"), Some("b")]));
+        graph.add_paper(&paper("p3", &[Some("b"), Some("c")]));
+
+        let components = graph.connected_components();
+        assert_eq!(components, vec![vec!["a", "b", "c"], vec!["d", "e"]]);
+    }
+}
@@ -0,0 +1,646 @@
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::client::BaseClient;
+use crate::error::ApiError;
+use crate::v1::definition::{BasePaper, Citation, CitationIntent, Reference};
+use crate::v1::endpoint::{GetPaper, GetPaperCitations, GetPaperReferences, Timeouts};
+use crate::v1::error::ResponseError;
+use crate::v1::pagination::{Page, Results};
+use crate::v1::parameter::{BasePaperField, PaperInfoField};
+use crate::v1::query_params::{PaperCitationsParams, PaperParams, PaperReferencesParams};
+use crate::v1::utils::paper_fields_with;
+
+#[cfg(feature = "blocking")]
+pub use blocking::CrawlIter;
+
+#[cfg(feature = "async")]
+pub use r#async::CrawlAsyncIter;
+
+type CrawlError<C> = ApiError<ResponseError, <C as BaseClient>::Error>;
+
+// Only the embedding versions of the paper endpoint are exclusive,
+// so lists of base paper fields are never rejected.
+static FIELDS_MSG: &str = "base paper fields are accepted by every paper endpoint";
+
+/// Which links of a paper are followed while crawling.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// Follow the papers that cite the current paper.
+    Citations,
+    /// Follow the papers that are cited by the current paper.
+    References,
+    /// Follow both citations and references.
+    Both,
+}
+
+/// The order in which discovered papers are expanded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Traversal {
+    /// Expand every paper at depth `n` before any paper at depth `n + 1`.
+    BreadthFirst,
+    /// Expand the most recently discovered paper first.
+    DepthFirst,
+}
+
+/// Configuration for a [`Crawler`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrawlOptions {
+    /// Which links are followed.
+    pub direction: Direction,
+    /// The expansion order.
+    pub traversal: Traversal,
+    /// Papers at this depth are emitted but not expanded.
+    /// Seeds are at depth 0, so a `max_depth` of 0 only emits the seeds.
+    pub max_depth: u32,
+    /// Maximum number of neighbors kept per paper and per direction.
+    pub fan_out: Results,
+    /// Only follow links flagged as influential by the API.
+    pub influential_only: bool,
+    /// Paper fields requested for every emitted node.
+    /// `paperId` is always requested since it identifies the nodes.
+    pub fields: Vec<BasePaperField>,
+    /// The timeout of every request.
+    pub timeout: Option<Duration>,
+    /// The maximum duration of the crawl, from the call to `crawl`. Once reached,
+    /// the crawl returns [`ApiError::Timeout`] and the papers and links fetched
+    /// until then, and stops.
+    pub max_duration: Option<Duration>,
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        CrawlOptions {
+            direction: Direction::Citations,
+            traversal: Traversal::BreadthFirst,
+            max_depth: 1,
+            fan_out: Results::Limit(Page::default().get_limit()),
+            influential_only: false,
+            fields: vec![PaperInfoField::Title.into()],
+            timeout: None,
+            max_duration: None,
+        }
+    }
+}
+
+/// A paper reached by the crawler.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrawlNode {
+    /// Number of links followed from a seed to this paper, when it was first
+    /// reached. With [`Traversal::BreadthFirst`] it is the distance to the
+    /// closest seed, while with [`Traversal::DepthFirst`] a shorter path may
+    /// be found after the node is emitted.
+    pub depth: u32,
+    /// The paper with the requested fields.
+    pub paper: BasePaper,
+}
+
+/// A citation link between two crawled papers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrawlEdge {
+    /// ID of the paper that cites.
+    pub citing: String,
+    /// ID of the paper that is cited.
+    pub cited: String,
+    /// Text snippets where the citation occurs.
+    pub contexts: Option<HashSet<String>>,
+    /// Citation intents.
+    pub intents: Option<HashSet<CitationIntent>>,
+    // See: https://www.semanticscholar.org/faq#influential-citations.
+    pub is_influential: Option<bool>,
+}
+
+/// An item emitted by the crawler.
+///
+/// A node is always emitted before any edge that refers to it.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CrawlEvent {
+    Node(CrawlNode),
+    Edge(CrawlEdge),
+}
+
+/// A citation graph crawler that expands a set of seed papers
+/// through the paper citations and/or references endpoints.
+///
+/// Every paper is visited at most once, even if it is reachable from
+/// multiple seeds, and every edge is emitted at most once.
+#[derive(Debug)]
+pub struct Crawler {
+    seeds: VecDeque<String>,
+    options: CrawlOptions,
+}
+
+impl Crawler {
+    pub fn new(seeds: impl IntoIterator<Item = String>, options: CrawlOptions) -> Crawler {
+        Crawler { seeds: seeds.into_iter().collect(), options }
+    }
+}
+
+/// A link returned by either the citations or references endpoints.
+struct Link {
+    paper: Option<BasePaper>,
+    contexts: Option<HashSet<String>>,
+    intents: Option<HashSet<CitationIntent>>,
+    is_influential: Option<bool>,
+}
+
+impl From<Citation> for Link {
+    fn from(citation: Citation) -> Link {
+        Link {
+            paper: citation.citing_paper,
+            contexts: citation.contexts,
+            intents: citation.intents,
+            is_influential: citation.is_influential,
+        }
+    }
+}
+
+impl From<Reference> for Link {
+    fn from(reference: Reference) -> Link {
+        Link {
+            paper: reference.cited_paper,
+            contexts: reference.contexts,
+            intents: reference.intents,
+            is_influential: reference.is_influential,
+        }
+    }
+}
+
+/// Traversal bookkeeping shared by the blocking and async crawlers.
+#[derive(Debug)]
+struct CrawlState {
+    options: CrawlOptions,
+    seeds: VecDeque<String>,
+    frontier: VecDeque<(String, u32)>,
+    visited: HashSet<String>,
+    edges: HashSet<(String, String)>,
+    pending: VecDeque<CrawlEvent>,
+    deadline: Option<Instant>,
+    // Set when a request failed after the deadline.
+    expired: bool,
+}
+
+impl CrawlState {
+    fn new(crawler: Crawler) -> Self {
+        let deadline = crawler.options.max_duration.and_then(|d| Instant::now().checked_add(d));
+        CrawlState {
+            deadline,
+            expired: false,
+            options: crawler.options,
+            seeds: crawler.seeds,
+            frontier: VecDeque::new(),
+            visited: HashSet::new(),
+            edges: HashSet::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn fields(&self) -> impl Iterator<Item = BasePaperField> + '_ {
+        let id = BasePaperField::from(PaperInfoField::PaperId);
+        std::iter::once(id).chain(self.options.fields.iter().copied().filter(move |f| *f != id))
+    }
+
+    fn paper_params(&self) -> PaperParams {
+        PaperParams::new(Some(self.fields())).expect(FIELDS_MSG)
+    }
+
+    fn citations_params(&self) -> PaperCitationsParams {
+        PaperCitationsParams::new(Some(paper_fields_with(self.fields())), Page::default())
+            .expect(FIELDS_MSG)
+    }
+
+    fn references_params(&self) -> PaperReferencesParams {
+        PaperReferencesParams::new(Some(paper_fields_with(self.fields())), Page::default())
+            .expect(FIELDS_MSG)
+    }
+
+    /// Applies the request timeout and the crawl deadline to `endpoint`.
+    fn with_timeouts<E: Timeouts>(&self, mut endpoint: E) -> E {
+        endpoint.set_timeouts(self.options.timeout, self.deadline);
+        endpoint
+    }
+
+    fn paper_endpoint(&self, seed: String) -> GetPaper {
+        self.with_timeouts(GetPaper::new(self.paper_params(), seed))
+    }
+
+    fn citations_endpoint(&self, paper_id: &str) -> GetPaperCitations {
+        self.with_timeouts(GetPaperCitations::new(self.citations_params(), paper_id.into()))
+    }
+
+    fn references_endpoint(&self, paper_id: &str) -> GetPaperReferences {
+        self.with_timeouts(GetPaperReferences::new(self.references_params(), paper_id.into()))
+    }
+
+    /// Stops the crawl if `err` happened after the deadline.
+    fn fail<E>(&mut self, err: E) -> E {
+        self.expired = self.deadline.map_or(false, |deadline| Instant::now() >= deadline);
+        err
+    }
+
+    fn follows_citations(&self) -> bool {
+        matches!(self.options.direction, Direction::Citations | Direction::Both)
+    }
+
+    fn follows_references(&self) -> bool {
+        matches!(self.options.direction, Direction::References | Direction::Both)
+    }
+
+    /// The maximum number of kept neighbors per direction.
+    fn fan_out(&self) -> usize {
+        match self.options.fan_out {
+            Results::All => usize::MAX,
+            Results::Limit(limit) => usize::try_from(limit).unwrap_or(usize::MAX),
+        }
+    }
+
+    fn keeps(&self, link: &Link) -> bool {
+        !self.options.influential_only || link.is_influential == Some(true)
+    }
+
+    fn pop_pending(&mut self) -> Option<CrawlEvent> {
+        self.pending.pop_front()
+    }
+
+    fn next_seed(&mut self) -> Option<String> {
+        self.seeds.pop_front()
+    }
+
+    /// Pops the next paper to expand, skipping the ones at `max_depth`.
+    fn next_expansion(&mut self) -> Option<(String, u32)> {
+        loop {
+            let next = match self.options.traversal {
+                Traversal::BreadthFirst => self.frontier.pop_front(),
+                Traversal::DepthFirst => self.frontier.pop_back(),
+            }?;
+            if next.1 < self.options.max_depth {
+                return Some(next);
+            }
+        }
+    }
+
+    /// Registers a paper as visited, returning `false` if it was already known.
+    fn visit(&mut self, paper: BasePaper, depth: u32) -> bool {
+        let paper_id = match paper.info.paper_id {
+            Some(ref id) => id.clone(),
+            None => return false,
+        };
+        if !self.visited.insert(paper_id.clone()) {
+            return false;
+        }
+        self.frontier.push_back((paper_id, depth));
+        self.pending.push_back(CrawlEvent::Node(CrawlNode { depth, paper }));
+        true
+    }
+
+    fn visit_seed(&mut self, seed: String, mut paper: BasePaper) {
+        // The API may not return an ID for papers requested through an external ID.
+        paper.info.paper_id.get_or_insert(seed);
+        self.visit(paper, 0);
+    }
+
+    /// Visits the paper at the other end of `link`.
+    /// `outgoing` is `true` if `paper_id` cites the linked paper.
+    fn visit_link(&mut self, paper_id: &str, depth: u32, link: Link, outgoing: bool) {
+        let paper = match link.paper {
+            Some(paper) if paper.info.paper_id.is_some() => paper,
+            _ => return,
+        };
+        let other = paper.info.paper_id.clone().unwrap_or_default();
+        self.visit(paper, depth + 1);
+
+        let (citing, cited) =
+            if outgoing { (paper_id.to_owned(), other) } else { (other, paper_id.to_owned()) };
+        if self.edges.insert((citing.clone(), cited.clone())) {
+            self.pending.push_back(CrawlEvent::Edge(CrawlEdge {
+                citing,
+                cited,
+                contexts: link.contexts,
+                intents: link.intents,
+                is_influential: link.is_influential,
+            }));
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use super::*;
+    use crate::client::Client;
+    use crate::v1::definition::FullPaper;
+
+    impl Crawler {
+        pub fn crawl<C>(self, client: &C) -> CrawlIter<'_, C> {
+            CrawlIter { state: CrawlState::new(self), client }
+        }
+    }
+
+    pub struct CrawlIter<'a, C> {
+        state: CrawlState,
+        client: &'a C,
+    }
+
+    impl<C> CrawlIter<'_, C>
+    where
+        C: Client,
+        CrawlError<C>: From<C::Error>,
+    {
+        fn expand(&mut self, paper_id: &str, depth: u32) -> Result<(), CrawlError<C>> {
+            let fan_out = self.state.fan_out();
+
+            if self.state.follows_citations() {
+                let endpoint = self.state.citations_endpoint(paper_id);
+                let mut kept = 0;
+                for citation in endpoint.paged::<Citation, C>(Results::All, self.client) {
+                    if kept >= fan_out {
+                        break;
+                    }
+                    let link = Link::from(citation?);
+                    if self.state.keeps(&link) {
+                        self.state.visit_link(paper_id, depth, link, false);
+                        kept += 1;
+                    }
+                }
+            }
+
+            if self.state.follows_references() {
+                let endpoint = self.state.references_endpoint(paper_id);
+                let mut kept = 0;
+                for reference in endpoint.paged::<Reference, C>(Results::All, self.client) {
+                    if kept >= fan_out {
+                        break;
+                    }
+                    let link = Link::from(reference?);
+                    if self.state.keeps(&link) {
+                        self.state.visit_link(paper_id, depth, link, true);
+                        kept += 1;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<C> Iterator for CrawlIter<'_, C>
+    where
+        C: Client,
+        CrawlError<C>: From<C::Error>,
+    {
+        type Item = Result<CrawlEvent, CrawlError<C>>;
+
+        // An error aborts the expansion of the current paper only,
+        // the crawl resumes from the next paper in the frontier,
+        // unless the deadline was reached.
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(event) = self.state.pop_pending() {
+                    return Some(Ok(event));
+                }
+
+                if self.state.expired {
+                    return None;
+                }
+
+                if let Some(seed) = self.state.next_seed() {
+                    let endpoint = self.state.paper_endpoint(seed.clone());
+                    match endpoint.query::<FullPaper, C>(self.client) {
+                        Ok(paper) => self.state.visit_seed(seed, paper.into()),
+                        Err(err) => return Some(Err(self.state.fail(err))),
+                    }
+                    continue;
+                }
+
+                let (paper_id, depth) = self.state.next_expansion()?;
+                if let Err(err) = self.expand(&paper_id, depth) {
+                    return Some(Err(self.state.fail(err)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use futures_core::Stream;
+
+    use super::*;
+    use crate::client::AsyncClient;
+    use crate::v1::definition::FullPaper;
+    use crate::v1::endpoint::iter::Next;
+
+    impl Crawler {
+        pub fn crawl_async<C>(self, client: &C) -> CrawlAsyncIter<'_, C>
+        where
+            C: AsyncClient + Sync,
+            CrawlError<C>: From<C::Error>,
+        {
+            let inner = InnerCrawl { state: CrawlState::new(self), client };
+            CrawlAsyncIter { inner: Some(inner), future: None }
+        }
+    }
+
+    struct InnerCrawl<'c, C> {
+        state: CrawlState,
+        client: &'c C,
+    }
+
+    impl<C> InnerCrawl<'_, C>
+    where
+        C: AsyncClient + Sync,
+        CrawlError<C>: From<C::Error>,
+    {
+        async fn expand(&mut self, paper_id: &str, depth: u32) -> Result<(), CrawlError<C>> {
+            let fan_out = self.state.fan_out();
+
+            if self.state.follows_citations() {
+                let endpoint = self.state.citations_endpoint(paper_id);
+                let mut stream = endpoint.paged_async::<Citation, C>(Results::All, self.client);
+                let mut kept = 0;
+                while kept < fan_out {
+                    let link = match Next(&mut stream).await {
+                        Some(citation) => Link::from(citation?),
+                        None => break,
+                    };
+                    if self.state.keeps(&link) {
+                        self.state.visit_link(paper_id, depth, link, false);
+                        kept += 1;
+                    }
+                }
+            }
+
+            if self.state.follows_references() {
+                let endpoint = self.state.references_endpoint(paper_id);
+                let mut stream = endpoint.paged_async::<Reference, C>(Results::All, self.client);
+                let mut kept = 0;
+                while kept < fan_out {
+                    let link = match Next(&mut stream).await {
+                        Some(reference) => Link::from(reference?),
+                        None => break,
+                    };
+                    if self.state.keeps(&link) {
+                        self.state.visit_link(paper_id, depth, link, true);
+                        kept += 1;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        // An error aborts the expansion of the current paper only,
+        // the crawl resumes from the next paper in the frontier,
+        // unless the deadline was reached.
+        async fn next_async(&mut self) -> Option<Result<CrawlEvent, CrawlError<C>>> {
+            loop {
+                if let Some(event) = self.state.pop_pending() {
+                    return Some(Ok(event));
+                }
+
+                if self.state.expired {
+                    return None;
+                }
+
+                if let Some(seed) = self.state.next_seed() {
+                    let endpoint = self.state.paper_endpoint(seed.clone());
+                    match endpoint.query_async::<FullPaper, C>(self.client).await {
+                        Ok(paper) => self.state.visit_seed(seed, paper.into()),
+                        Err(err) => return Some(Err(self.state.fail(err))),
+                    }
+                    continue;
+                }
+
+                let (paper_id, depth) = self.state.next_expansion()?;
+                if let Err(err) = self.expand(&paper_id, depth).await {
+                    return Some(Err(self.state.fail(err)));
+                }
+            }
+        }
+    }
+
+    type PinnedBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+    type FutureOutput<'c, C> = Option<(Result<CrawlEvent, CrawlError<C>>, InnerCrawl<'c, C>)>;
+
+    pub struct CrawlAsyncIter<'c, C: BaseClient> {
+        inner: Option<InnerCrawl<'c, C>>,
+        future: Option<PinnedBoxFuture<'c, FutureOutput<'c, C>>>,
+    }
+
+    impl<'c, C> Stream for CrawlAsyncIter<'c, C>
+    where
+        C: AsyncClient + Sync,
+        CrawlError<C>: From<C::Error>,
+    {
+        type Item = Result<CrawlEvent, CrawlError<C>>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            if let Some(mut inner) = self.inner.take() {
+                self.future =
+                    Some(Box::pin(
+                        async move { inner.next_async().await.map(|item| (item, inner)) },
+                    ));
+            }
+
+            let step = match self.future.as_mut() {
+                Some(future) => futures_core::ready!(future.as_mut().poll(cx)),
+                None => panic!("Stream must not be polled after it returned `Poll::Ready(None)`"),
+            };
+
+            self.future = None;
+            match step {
+                Some((item, inner)) => {
+                    self.inner = Some(inner);
+                    Poll::Ready(Some(item))
+                }
+                None => Poll::Ready(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paper(id: &str) -> BasePaper {
+        let mut paper = BasePaper::default();
+        paper.info.paper_id = Some(id.to_owned());
+        paper
+    }
+
+    fn link(id: &str, is_influential: Option<bool>) -> Link {
+        Link { paper: Some(paper(id)), contexts: None, intents: None, is_influential }
+    }
+
+    fn state(options: CrawlOptions) -> CrawlState {
+        CrawlState::new(Crawler::new(vec!["a".to_owned()], options))
+    }
+
+    #[test]
+    fn visited_papers_are_emitted_once() {
+        let mut state = state(CrawlOptions::default());
+        state.visit_seed("a".to_owned(), paper("a"));
+        state.visit_link("a", 0, link("b", None), false);
+        state.visit_link("a", 0, link("b", None), false);
+
+        let events: Vec<_> = state.pending.drain(..).collect();
+        let nodes = events.iter().filter(|e| matches!(e, CrawlEvent::Node(_))).count();
+        let edges = events.iter().filter(|e| matches!(e, CrawlEvent::Edge(_))).count();
+        assert_eq!((nodes, edges), (2, 1));
+    }
+
+    #[test]
+    fn edges_follow_link_direction() {
+        let mut state = state(CrawlOptions::default());
+        state.visit_link("a", 0, link("b", None), false);
+        state.visit_link("a", 0, link("c", None), true);
+
+        let edges: Vec<_> = state
+            .pending
+            .drain(..)
+            .filter_map(|e| match e {
+                CrawlEvent::Edge(edge) => Some((edge.citing, edge.cited)),
+                CrawlEvent::Node(_) => None,
+            })
+            .collect();
+        let expected = vec![("b".to_owned(), "a".to_owned()), ("a".to_owned(), "c".to_owned())];
+        assert_eq!(edges, expected);
+    }
+
+    #[test]
+    fn expansion_stops_at_max_depth() {
+        let options = CrawlOptions { max_depth: 1, ..CrawlOptions::default() };
+        let mut state = state(options);
+        state.visit_seed("a".to_owned(), paper("a"));
+        state.visit_link("a", 0, link("b", None), false);
+
+        assert_eq!(state.next_expansion(), Some(("a".to_owned(), 0)));
+        assert_eq!(state.next_expansion(), None);
+    }
+
+    #[test]
+    fn depth_first_expands_latest_discovery() {
+        let options =
+            CrawlOptions { traversal: Traversal::DepthFirst, max_depth: 3, ..Default::default() };
+        let mut state = state(options);
+        state.visit_link("a", 0, link("b", None), false);
+        state.visit_link("a", 0, link("c", None), false);
+
+        assert_eq!(state.next_expansion(), Some(("c".to_owned(), 1)));
+    }
+
+    #[test]
+    fn influential_filter_rejects_unknown_links() {
+        let options = CrawlOptions { influential_only: true, ..CrawlOptions::default() };
+        let state = state(options);
+
+        assert!(state.keeps(&link("b", Some(true))));
+        assert!(!state.keeps(&link("b", Some(false))));
+        assert!(!state.keeps(&link("b", None)));
+    }
+}
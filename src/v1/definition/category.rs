@@ -0,0 +1,110 @@
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// Defines an enum of known API string values, with a fallback
+/// variant that keeps the values added to the API later on.
+macro_rules! define_string_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident { $($variant:ident => $value:literal,)* }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, DeserializeFromStr, Eq, Hash, Ord, PartialEq, PartialOrd, SerializeDisplay)]
+        pub enum $name {
+            $($variant,)*
+            /// A value that is not known by this version of the crate.
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(value) => value,
+                }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $($value => Self::$variant,)*
+                    other => Self::Other(other.to_owned()),
+                })
+            }
+        }
+    };
+}
+
+define_string_enum! {
+    /// A high-level academic category.
+    pub enum FieldOfStudy {
+        ComputerScience => "Computer Science",
+        Medicine => "Medicine",
+        Chemistry => "Chemistry",
+        Biology => "Biology",
+        MaterialsScience => "Materials Science",
+        Physics => "Physics",
+        Geology => "Geology",
+        Psychology => "Psychology",
+        Art => "Art",
+        History => "History",
+        Geography => "Geography",
+        Sociology => "Sociology",
+        Business => "Business",
+        PoliticalScience => "Political Science",
+        Economics => "Economics",
+        Philosophy => "Philosophy",
+        Mathematics => "Mathematics",
+        Engineering => "Engineering",
+        EnvironmentalScience => "Environmental Science",
+        AgriculturalAndFoodSciences => "Agricultural and Food Sciences",
+        Education => "Education",
+        Law => "Law",
+        Linguistics => "Linguistics",
+    }
+}
+
+define_string_enum! {
+    /// The intent of a citation, see: https://www.semanticscholar.org/faq#citation-intent.
+    pub enum CitationIntent {
+        Background => "background",
+        Methodology => "methodology",
+        Result => "result",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_values_are_kept() {
+        let fields: Vec<FieldOfStudy> =
+            serde_json::from_str(r#"["Computer Science", "Astrology"]"#).unwrap();
+        assert_eq!(
+            fields,
+            [FieldOfStudy::ComputerScience, FieldOfStudy::Other("Astrology".into())]
+        );
+        assert_eq!(serde_json::to_string(&fields).unwrap(), r#"["Computer Science","Astrology"]"#);
+        assert_eq!("result".parse(), Ok(CitationIntent::Result));
+    }
+
+    #[test]
+    fn api_names_are_known() {
+        let field: FieldOfStudy =
+            serde_json::from_str(r#""Agricultural and Food Sciences""#).unwrap();
+        assert_eq!(field, FieldOfStudy::AgriculturalAndFoodSciences);
+    }
+}
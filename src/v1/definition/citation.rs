@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, skip_serializing_none};
 
-use super::{batch::Batch, paper::BasePaper, Batched};
+use super::{batch::Batch, category::CitationIntent, paper::BasePaper, Batched};
 
 #[serde_as]
 #[skip_serializing_none]
@@ -15,7 +15,7 @@ pub struct Citation {
     // List of contexts.
     pub contexts: Option<HashSet<String>>,
     // List of intents.
-    pub intents: Option<HashSet<String>>,
+    pub intents: Option<HashSet<CitationIntent>>,
     // See: https://www.semanticscholar.org/faq#influential-citations.
     pub is_influential: Option<bool>,
 }
@@ -0,0 +1,127 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// A publication date that may be missing its day, or both its month and day.
+///
+/// Dates are ordered chronologically, and a partial date is ordered
+/// before the full dates within the same year or month.
+#[derive(
+    Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, Ord, PartialEq, PartialOrd, SerializeDisplay,
+)]
+pub struct PartialDate {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("invalid date `{0}`, expected YYYY, YYYY-MM or YYYY-MM-DD")]
+pub struct ParseDateError(String);
+
+impl PartialDate {
+    /// Returns `None` if the month or the day are out of range,
+    /// or if there is a day without a month.
+    pub fn new(year: u16, month: Option<u8>, day: Option<u8>) -> Option<PartialDate> {
+        let valid_month = month.map_or(true, |month| (1..=12).contains(&month));
+        let valid_day = match (month, day) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(month), Some(day)) => (1..=days_in_month(year, month)).contains(&day),
+        };
+        if valid_month && valid_day {
+            Some(PartialDate { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    pub fn from_year(year: u16) -> PartialDate {
+        PartialDate { year, month: None, day: None }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Display for PartialDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PartialDate {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseDateError(s.to_owned());
+        let mut parts = s.trim().split('-');
+        let mut next = |len| match parts.next() {
+            Some(part) if part.len() == len && part.bytes().all(|b| b.is_ascii_digit()) => {
+                part.parse().map(Some).map_err(|_| err())
+            }
+            Some(_) => Err(err()),
+            None => Ok(None),
+        };
+        let year = next(4)?.ok_or_else(err)?;
+        let month = next(2)?.map(|month: u16| month as u8);
+        let day = next(2)?.map(|day: u16| day as u8);
+        if parts.next().is_some() {
+            return Err(err());
+        }
+        PartialDate::new(year, month, day).ok_or_else(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_dates_are_parsed() {
+        let date = |s: &str| s.parse::<PartialDate>();
+        assert_eq!(date("2017"), Ok(PartialDate::from_year(2017)));
+        assert_eq!(date("2017-06"), Ok(PartialDate::new(2017, Some(6), None).unwrap()));
+        assert_eq!(date("2020-02-29").map(|date| date.to_string()).as_deref(), Ok("2020-02-29"));
+        assert!(date("2019-02-29").is_err());
+        assert!(date("2017-6").is_err());
+        assert!(date("2017-06-12-01").is_err());
+        assert!(date("").is_err());
+    }
+
+    #[test]
+    fn partial_dates_are_ordered_chronologically() {
+        let mut dates: Vec<PartialDate> = ["2018-01-02", "2017", "2018", "2017-12-31", "2018-01"]
+            .map(|s| s.parse().unwrap())
+            .to_vec();
+        dates.sort();
+        let dates: Vec<_> = dates.iter().map(ToString::to_string).collect();
+        assert_eq!(dates, ["2017", "2017-12-31", "2018", "2018-01", "2018-01-02"]);
+    }
+}
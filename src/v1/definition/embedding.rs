@@ -1,7 +1,66 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, skip_serializing_none};
+use serde_with::{serde_as, skip_serializing_none, DeserializeFromStr, SerializeDisplay};
 
 use super::paper::FullPaper;
+use crate::v1::parameter::EmbeddingVersion;
+
+/// The model that produced an [`Embedding`], so that embeddings
+/// from different models are not compared with each other.
+#[derive(Clone, Debug, DeserializeFromStr, Eq, Hash, PartialEq, SerializeDisplay)]
+pub enum EmbeddingModel {
+    /// SPECTER, named `specter@v0.1.1` by the API.
+    SpecterV1,
+    /// SPECTER2, named `specter_v2` by the API.
+    SpecterV2,
+    /// A model that is not known by this crate.
+    Other(String),
+}
+
+impl EmbeddingModel {
+    /// The version to request embeddings of this model with, if it is known.
+    pub fn version(&self) -> Option<EmbeddingVersion> {
+        match self {
+            Self::SpecterV1 => Some(EmbeddingVersion::SpecterV1),
+            Self::SpecterV2 => Some(EmbeddingVersion::SpecterV2),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl From<EmbeddingVersion> for EmbeddingModel {
+    fn from(version: EmbeddingVersion) -> EmbeddingModel {
+        match version {
+            EmbeddingVersion::SpecterV1 => EmbeddingModel::SpecterV1,
+            EmbeddingVersion::SpecterV2 => EmbeddingModel::SpecterV2,
+        }
+    }
+}
+
+impl Display for EmbeddingModel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::SpecterV1 => write!(f, "specter@v0.1.1"),
+            Self::SpecterV2 => write!(f, "specter_v2"),
+            Self::Other(model) => write!(f, "{}", model),
+        }
+    }
+}
+
+impl FromStr for EmbeddingModel {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "specter@v0.1.1" | "specter_v1" => Self::SpecterV1,
+            "specter_v2" => Self::SpecterV2,
+            _ => Self::Other(s.to_owned()),
+        })
+    }
+}
 
 #[serde_as]
 #[skip_serializing_none]
@@ -9,7 +68,7 @@ use super::paper::FullPaper;
 #[serde(rename_all = "camelCase", default)]
 pub struct Embedding {
     // The underlying model+version that produced the embedding.
-    pub model: Option<String>,
+    pub model: Option<EmbeddingModel>,
     // Numerical embedding vector.
     pub vector: Option<Vec<f64>>,
 }
@@ -19,3 +78,36 @@ impl From<FullPaper> for Embedding {
         paper.embedding.unwrap_or_default()
     }
 }
+
+/// An [`Embedding`] with its vector stored as `f32`, which halves its size.
+/// The API vectors, like the 768 dimensions of SPECTER, don't need more precision
+/// for similarity search, see [`crate::v1::similarity`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CompactEmbedding {
+    // The underlying model+version that produced the embedding.
+    pub model: Option<EmbeddingModel>,
+    // Numerical embedding vector.
+    pub vector: Option<Vec<f32>>,
+}
+
+impl From<Embedding> for CompactEmbedding {
+    fn from(embedding: Embedding) -> CompactEmbedding {
+        let vector = embedding.vector.map(|v| v.into_iter().map(|x| x as f32).collect());
+        CompactEmbedding { model: embedding.model, vector }
+    }
+}
+
+impl From<CompactEmbedding> for Embedding {
+    fn from(embedding: CompactEmbedding) -> Embedding {
+        let vector = embedding.vector.map(|v| v.into_iter().map(f64::from).collect());
+        Embedding { model: embedding.model, vector }
+    }
+}
+
+impl From<FullPaper> for CompactEmbedding {
+    fn from(paper: FullPaper) -> CompactEmbedding {
+        Embedding::from(paper).into()
+    }
+}
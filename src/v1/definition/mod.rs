@@ -4,9 +4,15 @@ pub use author::*;
 mod batch;
 pub(in crate::v1) use batch::*;
 
+mod category;
+pub use category::*;
+
 mod citation;
 pub use citation::*;
 
+mod date;
+pub use date::*;
+
 mod embedding;
 pub use embedding::*;
 
@@ -16,5 +22,8 @@ pub use paper::*;
 mod reference;
 pub use reference::*;
 
+mod requested;
+pub use requested::*;
+
 mod tldr;
 pub use tldr::*;
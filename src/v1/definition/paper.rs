@@ -5,6 +5,8 @@ use serde_with::{serde_as, skip_serializing_none};
 
 use super::author::{Author, AuthorInfo};
 use super::batch::{Batch, SearchBatch};
+use super::category::FieldOfStudy;
+use super::date::PartialDate;
 use super::embedding::Embedding;
 use super::tldr::Tldr;
 use super::Batched;
@@ -73,7 +75,30 @@ pub struct BasePaper {
     // See: https://www.openaccess.nl/en/what-is-open-access.
     pub is_open_access: Option<bool>,
     // A list of high-level academic categories.
-    pub fields_of_study: Option<HashSet<String>>,
+    pub fields_of_study: Option<HashSet<FieldOfStudy>>,
+    // Publication date in YYYY-MM-DD format, if known.
+    pub publication_date: Option<PartialDate>,
+    // Publication types, such as JournalArticle, Conference or Review.
+    pub publication_types: Option<Vec<String>>,
+    // Journal name, volume and pages, if known.
+    pub journal: Option<Journal>,
+    // Details about the paper's publication venue.
+    pub publication_venue: Option<PublicationVenue>,
+    // A link to a publicly available PDF of the paper, if any.
+    pub open_access_pdf: Option<OpenAccessPdf>,
+    // A list of academic categories, with the source of their classification.
+    #[serde(rename = "s2FieldsOfStudy")]
+    pub s2_fields_of_study: Option<Vec<S2FieldOfStudy>>,
+    // The paper's citation in formatted styles.
+    pub citation_styles: Option<CitationStyles>,
+}
+
+impl BasePaper {
+    /// The publication date, or just the year if the date is unknown.
+    pub fn date(&self) -> Option<PartialDate> {
+        let year = self.info.year.and_then(|year| u16::try_from(year).ok());
+        self.publication_date.or_else(|| year.map(PartialDate::from_year))
+    }
 }
 
 impl From<PaperWithLinks> for BasePaper {
@@ -113,6 +138,72 @@ pub struct PaperExternalId {
     pub corpus_id: Option<u64>,
 }
 
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Journal {
+    // Journal name.
+    pub name: Option<String>,
+    // Journal volume.
+    pub volume: Option<String>,
+    // Page range within the volume, like 1-10.
+    pub pages: Option<String>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PublicationVenue {
+    // Venue's ID in Semantic Scholar.
+    pub id: Option<String>,
+    // Venue's name.
+    pub name: Option<String>,
+    // Venue's type, such as journal or conference.
+    #[serde(rename = "type")]
+    pub r#type: Option<String>,
+    // Other names the venue is known by.
+    pub alternate_names: Option<Vec<String>>,
+    // Venue's ISSN.
+    pub issn: Option<String>,
+    // Venue's website.
+    pub url: Option<String>,
+    // Other websites of the venue.
+    pub alternate_urls: Option<Vec<String>>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OpenAccessPdf {
+    // Link to the PDF.
+    pub url: Option<String>,
+    // Open access status, such as GREEN, GOLD, HYBRID or BRONZE.
+    pub status: Option<String>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct S2FieldOfStudy {
+    // Academic category, such as Computer Science.
+    pub category: Option<FieldOfStudy>,
+    // Source of the classification, external or s2-fos-model.
+    pub source: Option<String>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CitationStyles {
+    // The paper's citation as a BibTeX entry.
+    pub bibtex: Option<String>,
+}
+
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
@@ -120,9 +211,10 @@ pub struct PaperExternalId {
 pub struct PaperWithLinks {
     #[serde(flatten)]
     pub base: BasePaper,
-    pub authors: HashSet<AuthorInfo>,
-    pub citations: Vec<PaperInfo>,
-    pub references: Vec<PaperInfo>,
+    // The authors, in the order of the paper's byline.
+    pub authors: Vec<AuthorInfo>,
+    pub citations: Vec<BasePaper>,
+    pub references: Vec<BasePaper>,
 }
 
 #[serde_as]
@@ -133,8 +225,8 @@ pub struct FullPaper {
     #[serde(flatten)]
     pub base: BasePaper,
     pub authors: Vec<Author>,
-    pub citations: Vec<PaperInfo>,
-    pub references: Vec<PaperInfo>,
+    pub citations: Vec<BasePaper>,
+    pub references: Vec<BasePaper>,
     pub embedding: Option<Embedding>,
     pub tldr: Option<Tldr>,
 }
@@ -0,0 +1,165 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// The state of a response field, telling apart a field
+/// that was not requested from one that the API returned as `null`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FieldState<T> {
+    /// The field was not requested, so its value is unknown.
+    NotRequested,
+    /// The field was requested, but the API returned no value for it.
+    Null,
+    /// The field was requested and returned.
+    Value(T),
+}
+
+impl<T> FieldState<T> {
+    pub fn new(requested: bool, value: Option<T>) -> FieldState<T> {
+        match (requested, value) {
+            (false, _) => FieldState::NotRequested,
+            (true, None) => FieldState::Null,
+            (true, Some(value)) => FieldState::Value(value),
+        }
+    }
+
+    pub fn is_requested(&self) -> bool {
+        !matches!(self, FieldState::NotRequested)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, FieldState::Null)
+    }
+
+    /// The field value, if it was requested and returned.
+    pub fn value(self) -> Option<T> {
+        match self {
+            FieldState::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_ref(&self) -> FieldState<&T> {
+        match self {
+            FieldState::NotRequested => FieldState::NotRequested,
+            FieldState::Null => FieldState::Null,
+            FieldState::Value(value) => FieldState::Value(value),
+        }
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> FieldState<U> {
+        match self {
+            FieldState::NotRequested => FieldState::NotRequested,
+            FieldState::Null => FieldState::Null,
+            FieldState::Value(value) => FieldState::Value(f(value)),
+        }
+    }
+}
+
+/// The names of the fields sent in a request, including the
+/// ones returned by the API when no fields are specified.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RequestedFields(BTreeSet<String>);
+
+impl RequestedFields {
+    pub(in crate::v1) fn new(names: impl IntoIterator<Item = String>) -> RequestedFields {
+        RequestedFields(names.into_iter().collect())
+    }
+
+    /// Whether `field` was requested. A top level field like `authors` is
+    /// considered requested if any of its sub-fields, like `authors.name`, was.
+    pub fn contains(&self, field: impl Display) -> bool {
+        let field = field.to_string();
+        self.0.iter().any(|name| {
+            *name == field || name.split_once('.').map_or(false, |(top, _)| top == field)
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+/// A response value along with the fields requested for it.
+///
+/// # Example
+///
+/// ```ignore
+/// let paper: WithFields<FullPaper> = endpoint.query_with_fields(&client)?;
+/// match paper.field(PaperInfoField::Venue, |paper| paper.base.info.venue.as_ref()) {
+///     FieldState::Value(venue) => println!("venue: {}", venue),
+///     FieldState::Null => println!("no venue"),
+///     FieldState::NotRequested => println!("venue was not requested"),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithFields<T> {
+    value: T,
+    fields: RequestedFields,
+}
+
+impl<T> WithFields<T> {
+    /// Pairs a `value` with the `fields` of the request that returned it,
+    /// see the `requested` method of the query parameters.
+    pub fn new(value: T, fields: RequestedFields) -> WithFields<T> {
+        WithFields { value, fields }
+    }
+
+    /// The state of `field`, read from the value by `get`.
+    pub fn field<'a, V, F>(
+        &'a self,
+        field: F,
+        get: impl FnOnce(&'a T) -> Option<&'a V>,
+    ) -> FieldState<&'a V>
+    where
+        F: Display,
+    {
+        FieldState::new(self.fields.contains(field), get(&self.value))
+    }
+
+    pub fn fields(&self) -> &RequestedFields {
+        &self.fields
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> WithFields<U> {
+        WithFields { value: f(self.value), fields: self.fields }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::BasePaper;
+    use crate::v1::parameter::{BasePaperField, PaperInfoField};
+
+    #[test]
+    fn null_fields_are_told_apart_from_not_requested_ones() {
+        let fields = RequestedFields::new(vec!["paperId".to_owned(), "venue".to_owned()]);
+        let mut paper = BasePaper::default();
+        paper.info.paper_id = Some("p1".to_owned());
+        let paper = WithFields::new(paper, fields);
+
+        let id = paper.field(PaperInfoField::PaperId, |paper| paper.info.paper_id.as_ref());
+        let venue = paper.field(PaperInfoField::Venue, |paper| paper.info.venue.as_ref());
+        let count =
+            paper.field(BasePaperField::CitationCount, |paper| paper.citation_count.as_ref());
+        assert_eq!(id.map(String::as_str), FieldState::Value("p1"));
+        assert_eq!(venue, FieldState::Null);
+        assert_eq!(count, FieldState::NotRequested);
+    }
+
+    #[test]
+    fn sub_fields_request_their_top_level_field() {
+        let fields = RequestedFields::new(vec!["authors.name".to_owned()]);
+        assert!(fields.contains("authors"));
+        assert!(fields.contains("authors.name"));
+        assert!(!fields.contains("authors.authorId"));
+    }
+}
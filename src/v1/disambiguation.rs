@@ -0,0 +1,391 @@
+//! Scoring of author records that may belong to the same person, like the
+//! results of an author search for a name.
+//!
+//! Authors are compared by their names and aliases, their affiliations and
+//! their co-authors. Co-authors are read from the papers of the search results,
+//! when requested with their authors, or from the papers of the author papers
+//! endpoint, see [`AuthorProfile::add_papers`].
+
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::v1::definition::{Author, AuthorInfo, AuthorWithPapers, PaperWithLinks};
+use crate::v1::text::fold;
+
+/// An author and the IDs of their co-authors.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AuthorProfile {
+    pub author: Author,
+    pub coauthors: HashSet<String>,
+}
+
+impl AuthorProfile {
+    pub fn new(author: Author) -> AuthorProfile {
+        AuthorProfile { author, coauthors: HashSet::new() }
+    }
+
+    fn add_coauthors<'a>(&mut self, authors: impl IntoIterator<Item = &'a AuthorInfo>) {
+        let id = self.author.info.author_id.as_ref();
+        let coauthors = authors.into_iter().filter_map(|author| author.author_id.as_ref());
+        self.coauthors.extend(coauthors.filter(|coauthor| Some(*coauthor) != id).cloned());
+    }
+
+    /// Adds the authors of `papers`, like the results of the author papers endpoint.
+    pub fn add_papers<'a>(&mut self, papers: impl IntoIterator<Item = &'a PaperWithLinks>) {
+        for paper in papers {
+            self.add_coauthors(&paper.authors);
+        }
+    }
+
+    /// The author name and aliases.
+    fn names(&self) -> impl Iterator<Item = &str> {
+        let aliases = self.author.aliases.iter().flatten();
+        self.author.info.name.iter().chain(aliases).map(String::as_str)
+    }
+}
+
+impl From<Author> for AuthorProfile {
+    fn from(author: Author) -> AuthorProfile {
+        AuthorProfile::new(author)
+    }
+}
+
+impl From<AuthorWithPapers> for AuthorProfile {
+    /// Keeps the co-authors of the papers, if they were requested with their authors.
+    fn from(author: AuthorWithPapers) -> AuthorProfile {
+        let mut profile = AuthorProfile::new(author.author);
+        for paper in author.papers.iter().flatten() {
+            profile.add_coauthors(paper.info.authors.iter().flatten());
+        }
+        profile
+    }
+}
+
+/// The relative weights of the signals of a comparison.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Weights {
+    pub name: f64,
+    pub aliases: f64,
+    pub affiliations: f64,
+    pub coauthors: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights { name: 0.4, aliases: 0.1, affiliations: 0.2, coauthors: 0.3 }
+    }
+}
+
+/// A piece of evidence that two authors are the same person.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Signal {
+    /// The names, or aliases, are compatible, like `J. Smith` and `John Smith`.
+    Name,
+    /// The normalized names and aliases overlap.
+    Aliases,
+    /// The affiliations share words.
+    Affiliations,
+    /// The authors share co-authors.
+    Coauthors,
+}
+
+/// The score of a [`Signal`], in `[0, 1]`, and why it was given.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explanation {
+    pub signal: Signal,
+    pub score: f64,
+    pub detail: String,
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?} ({:.2}): {}", self.signal, self.score, self.detail)
+    }
+}
+
+/// The comparison of two authors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    /// The weighted mean of the signal scores, in `[0, 1]`.
+    pub score: f64,
+    /// The signals that could be computed, since both authors
+    /// need aliases, affiliations or co-authors to compare them.
+    pub explanations: Vec<Explanation>,
+}
+
+/// A candidate ranked by [`Disambiguator::rank`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate<'a> {
+    pub profile: &'a AuthorProfile,
+    pub comparison: Comparison,
+}
+
+/// A pair of authors ranked by [`Disambiguator::pairs`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pair<'a> {
+    pub first: &'a AuthorProfile,
+    pub second: &'a AuthorProfile,
+    pub comparison: Comparison,
+}
+
+/// Compares author records with weighted signals.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Disambiguator {
+    weights: Weights,
+}
+
+impl Disambiguator {
+    pub fn new() -> Disambiguator {
+        Disambiguator::default()
+    }
+
+    pub fn with_weights(weights: Weights) -> Disambiguator {
+        Disambiguator { weights }
+    }
+
+    /// Scores how likely `a` and `b` are the same person.
+    ///
+    /// Only the signals available for both authors are weighted, so authors
+    /// without aliases, affiliations and co-authors are compared by name only.
+    pub fn compare(&self, a: &AuthorProfile, b: &AuthorProfile) -> Comparison {
+        let weighted = [
+            (self.weights.name, name_signal(a, b)),
+            (self.weights.aliases, aliases_signal(a, b)),
+            (self.weights.affiliations, affiliations_signal(a, b)),
+            (self.weights.coauthors, coauthors_signal(a, b)),
+        ];
+        let (mut total, mut weights) = (0.0, 0.0);
+        let mut explanations = Vec::new();
+        for (weight, explanation) in weighted {
+            if let Some(explanation) = explanation {
+                total += weight * explanation.score;
+                weights += weight;
+                explanations.push(explanation);
+            }
+        }
+        let score = if weights > 0.0 { total / weights } else { 0.0 };
+        Comparison { score, explanations }
+    }
+
+    /// Ranks the `candidates` by their score against `target`, from the most likely
+    /// match. Candidates with the same author ID as `target` are skipped.
+    pub fn rank<'a>(
+        &self,
+        target: &AuthorProfile,
+        candidates: &'a [AuthorProfile],
+    ) -> Vec<Candidate<'a>> {
+        let id = target.author.info.author_id.as_ref();
+        let mut ranked: Vec<_> = candidates
+            .iter()
+            .filter(|candidate| id.is_none() || candidate.author.info.author_id.as_ref() != id)
+            .map(|profile| Candidate { profile, comparison: self.compare(target, profile) })
+            .collect();
+        ranked.sort_by(|a, b| by_score(&a.comparison, &b.comparison));
+        ranked
+    }
+
+    /// Ranks every pair of `profiles`, like the results of an author search,
+    /// from the most likely duplicate.
+    pub fn pairs<'a>(&self, profiles: &'a [AuthorProfile]) -> Vec<Pair<'a>> {
+        let mut pairs = Vec::new();
+        for (i, first) in profiles.iter().enumerate() {
+            for second in &profiles[i + 1..] {
+                pairs.push(Pair { first, second, comparison: self.compare(first, second) });
+            }
+        }
+        pairs.sort_by(|a, b| by_score(&a.comparison, &b.comparison));
+        pairs
+    }
+}
+
+/// Orders comparisons from the highest score, which is never NaN.
+fn by_score(a: &Comparison, b: &Comparison) -> Ordering {
+    b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal)
+}
+
+/// Lowercases `text`, without accents on the common latin letters,
+/// and splits it into words.
+fn words(text: &str) -> Vec<String> {
+    let folded: String =
+        text.chars().flat_map(fold).map(|c| if c.is_alphanumeric() { c } else { ' ' }).collect();
+    folded.split_whitespace().map(str::to_owned).collect()
+}
+
+/// The words of a person name, with the family name last,
+/// so that `Smith, John` is read as `John Smith`.
+fn name_words(name: &str) -> Vec<String> {
+    match name.split_once(',') {
+        Some((family, given)) => words(given).into_iter().chain(words(family)).collect(),
+        None => words(name),
+    }
+}
+
+/// Scores two names: 1 if they are the same, 0.8 if they are compatible,
+/// like `J. Smith` and `John A. Smith`, and 0 otherwise.
+fn name_score(a: &[String], b: &[String]) -> f64 {
+    let ((a_family, a_given), (b_family, b_given)) = match (a.split_last(), b.split_last()) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return 0.0,
+    };
+    if a_family != b_family {
+        return 0.0;
+    }
+    if a_given == b_given {
+        return 1.0;
+    }
+    // Every given name of the shortest name must match one of the other,
+    // in order, either fully or by its initial.
+    let (short, long) =
+        if a_given.len() <= b_given.len() { (a_given, b_given) } else { (b_given, a_given) };
+    let mut long = long.iter();
+    let compatible = short.iter().all(|word| {
+        long.any(|other| {
+            word == other
+                || ((word.chars().count() == 1 || other.chars().count() == 1)
+                    && word.chars().next() == other.chars().next())
+        })
+    });
+    if compatible {
+        0.8
+    } else {
+        0.0
+    }
+}
+
+fn name_signal(a: &AuthorProfile, b: &AuthorProfile) -> Option<Explanation> {
+    let mut best: Option<(f64, &str, &str)> = None;
+    for a_name in a.names() {
+        for b_name in b.names() {
+            let score = name_score(&name_words(a_name), &name_words(b_name));
+            if best.map_or(true, |(best, ..)| score > best) {
+                best = Some((score, a_name, b_name));
+            }
+        }
+    }
+    let (score, a_name, b_name) = best?;
+    let detail = if score > 0.0 {
+        format!("`{}` matches `{}`", a_name, b_name)
+    } else {
+        format!("`{}` doesn't match `{}`", a_name, b_name)
+    };
+    Some(Explanation { signal: Signal::Name, score, detail })
+}
+
+/// The Jaccard index of two sets.
+fn jaccard<T: Ord>(a: &BTreeSet<T>, b: &BTreeSet<T>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+fn aliases_signal(a: &AuthorProfile, b: &AuthorProfile) -> Option<Explanation> {
+    if a.author.aliases.is_none() || b.author.aliases.is_none() {
+        return None;
+    }
+    let names = |profile: &AuthorProfile| -> BTreeSet<String> {
+        profile.names().map(|name| name_words(name).join(" ")).collect()
+    };
+    let (a, b) = (names(a), names(b));
+    let shared: Vec<_> = a.intersection(&b).map(String::as_str).collect();
+    let detail = format!("{} shared names and aliases: {}", shared.len(), shared.join(", "));
+    Some(Explanation { signal: Signal::Aliases, score: jaccard(&a, &b), detail })
+}
+
+fn affiliations_signal(a: &AuthorProfile, b: &AuthorProfile) -> Option<Explanation> {
+    const STOP_WORDS: [&str; 6] = ["of", "the", "and", "for", "de", "at"];
+    let affiliations = |profile: &AuthorProfile| -> Vec<(String, BTreeSet<String>)> {
+        let affiliations = profile.author.affiliations.iter().flatten();
+        affiliations
+            .map(|affiliation| {
+                let words = words(affiliation).into_iter();
+                let words = words.filter(|word| !STOP_WORDS.contains(&word.as_str())).collect();
+                (affiliation.clone(), words)
+            })
+            .collect()
+    };
+    let (a, b) = (affiliations(a), affiliations(b));
+    let mut best: Option<(f64, &str, &str)> = None;
+    for (a_name, a_words) in &a {
+        for (b_name, b_words) in &b {
+            let score = jaccard(a_words, b_words);
+            if best.map_or(true, |(best, ..)| score > best) {
+                best = Some((score, a_name, b_name));
+            }
+        }
+    }
+    let (score, a_name, b_name) = best?;
+    let detail = format!("`{}` and `{}` are the most similar affiliations", a_name, b_name);
+    Some(Explanation { signal: Signal::Affiliations, score, detail })
+}
+
+fn coauthors_signal(a: &AuthorProfile, b: &AuthorProfile) -> Option<Explanation> {
+    let min = a.coauthors.len().min(b.coauthors.len());
+    if min == 0 {
+        return None;
+    }
+    let shared = a.coauthors.intersection(&b.coauthors).count();
+    let detail = format!(
+        "{} shared co-authors, out of {} and {}",
+        shared,
+        a.coauthors.len(),
+        b.coauthors.len()
+    );
+    Some(Explanation { signal: Signal::Coauthors, score: shared as f64 / min as f64, detail })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(id: &str, name: &str, affiliations: &[&str], coauthors: &[&str]) -> AuthorProfile {
+        let mut author = Author::default();
+        author.info.author_id = Some(id.to_owned());
+        author.info.name = Some(name.to_owned());
+        if !affiliations.is_empty() {
+            author.affiliations = Some(affiliations.iter().map(|a| (*a).to_owned()).collect());
+        }
+        let coauthors = coauthors.iter().map(|c| (*c).to_owned()).collect();
+        AuthorProfile { author, coauthors }
+    }
+
+    #[test]
+    fn names_are_normalized_and_matched_by_initials() {
+        let score = |a: &str, b: &str| name_score(&name_words(a), &name_words(b));
+        assert_eq!(score("José García", "Garcia, Jose"), 1.0);
+        assert_eq!(score("J. A. Smith", "John Smith"), 0.8);
+        assert_eq!(score("John Smith", "Jane Smith"), 0.0);
+        assert_eq!(score("John Smith", "John Smyth"), 0.0);
+    }
+
+    #[test]
+    fn affiliations_are_split_at_commas() {
+        let a = profile("1", "Jane Doe", &["University of Oslo, Norway"], &[]);
+        let b = profile("2", "Jane Doe", &["University of Oslo"], &[]);
+        let explanation = affiliations_signal(&a, &b).unwrap();
+        assert!((explanation.score - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn candidates_are_ranked_with_explanations() {
+        let target = profile("1", "Jane Doe", &["University of Oslo"], &["a", "b", "c"]);
+        let candidates = [
+            profile("2", "J. Doe", &["Stanford University"], &["x"]),
+            profile("3", "Jane M. Doe", &["Dept. of Informatics, University of Oslo"], &["a", "b"]),
+            profile("4", "John Roe", &[], &[]),
+            profile("1", "Jane Doe", &[], &[]),
+        ];
+        let ranked = Disambiguator::new().rank(&target, &candidates);
+        let ids: Vec<_> =
+            ranked.iter().map(|c| c.profile.author.info.author_id.as_deref()).collect();
+        assert_eq!(ids, [Some("3"), Some("2"), Some("4")]);
+
+        let best = &ranked[0].comparison;
+        let signals: Vec<_> = best.explanations.iter().map(|e| e.signal).collect();
+        assert_eq!(signals, [Signal::Name, Signal::Affiliations, Signal::Coauthors]);
+        assert_eq!(best.explanations[2].score, 1.0);
+        assert_eq!(ranked[2].comparison.score, 0.0);
+    }
+}
@@ -7,8 +7,8 @@ use serde::de::DeserializeOwned;
 
 use crate::client::BaseClient;
 use crate::error::ApiError;
-use crate::v1::definition::AuthorWithPapers;
-use crate::v1::endpoint::BaseEndpoint;
+use crate::v1::definition::{AuthorWithPapers, WithFields};
+use crate::v1::endpoint::{impl_timeouts, BaseEndpoint};
 use crate::v1::error::ResponseError;
 use crate::v1::query_params::AuthorParams;
 use crate::v1::static_url::author_endpoint;
@@ -22,10 +22,19 @@ pub struct GetAuthor(AuthorEndpoint);
 impl GetAuthor {
     pub fn new(query_params: AuthorParams, author_id: String) -> GetAuthor {
         let endpoint = author_endpoint(&author_id);
-        GetAuthor(BaseEndpoint { query_params, endpoint })
+        GetAuthor(BaseEndpoint {
+            query_params,
+            endpoint,
+            name: "author",
+            id: Some(author_id),
+            timeout: None,
+            deadline: None,
+        })
     }
 }
 
+impl_timeouts!(GetAuthor);
+
 #[cfg(feature = "blocking")]
 mod blocking {
     use super::*;
@@ -40,6 +49,17 @@ mod blocking {
         {
             self.0.query(client).map(From::from)
         }
+
+        /// Like [`query`](Self::query), also returning the requested fields.
+        pub fn query_with_fields<T, C>(&self, client: &C) -> Result<WithFields<T>, AuthorError<C>>
+        where
+            T: From<AuthorWithPapers> + DeserializeOwned,
+            C: Client,
+            AuthorError<C>: From<C::Error>,
+        {
+            let fields = self.0.query_params.requested();
+            self.query(client).map(|value| WithFields::new(value, fields))
+        }
     }
 }
 
@@ -57,5 +77,19 @@ mod r#async {
         {
             self.0.query_async(client).await.map(From::from)
         }
+
+        /// Like [`query_async`](Self::query_async), also returning the requested fields.
+        pub async fn query_with_fields_async<T, C>(
+            &self,
+            client: &C,
+        ) -> Result<WithFields<T>, AuthorError<C>>
+        where
+            T: From<AuthorWithPapers> + DeserializeOwned,
+            C: AsyncClient + Sync,
+            AuthorError<C>: From<C::Error>,
+        {
+            let fields = self.0.query_params.requested();
+            self.query_async(client).await.map(|value| WithFields::new(value, fields))
+        }
     }
 }
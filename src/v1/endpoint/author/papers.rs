@@ -3,7 +3,7 @@ use serde::de::DeserializeOwned;
 use crate::client::BaseClient;
 use crate::error::ApiError;
 use crate::v1::definition::PaperWithLinks;
-use crate::v1::endpoint::{iter::BatchEndpointIter, BaseEndpoint};
+use crate::v1::endpoint::{impl_timeouts, iter::BatchEndpointIter, BaseEndpoint};
 use crate::v1::error::ResponseError;
 use crate::v1::pagination::Results;
 use crate::v1::query_params::AuthorPapersParams;
@@ -22,10 +22,19 @@ pub struct GetAuthorPapers(AuthorPapersEndpoint);
 impl GetAuthorPapers {
     pub fn new(query_params: AuthorPapersParams, author_id: String) -> GetAuthorPapers {
         let endpoint = author_papers_endpoint(&author_id);
-        GetAuthorPapers(BaseEndpoint { query_params, endpoint })
+        GetAuthorPapers(BaseEndpoint {
+            query_params,
+            endpoint,
+            name: "author_papers",
+            id: Some(author_id),
+            timeout: None,
+            deadline: None,
+        })
     }
 }
 
+impl_timeouts!(GetAuthorPapers);
+
 type AuthorPapersError<C> = ApiError<ResponseError, <C as BaseClient>::Error>;
 
 #[cfg(feature = "blocking")]
@@ -3,7 +3,7 @@ use serde::de::DeserializeOwned;
 use crate::client::BaseClient;
 use crate::error::ApiError;
 use crate::v1::definition::AuthorWithPapers;
-use crate::v1::endpoint::{iter::SearchBatchEndpointIter, BaseEndpoint};
+use crate::v1::endpoint::{impl_timeouts, iter::SearchBatchEndpointIter, BaseEndpoint};
 use crate::v1::error::ResponseError;
 use crate::v1::pagination::Results;
 use crate::v1::query_params::AuthorSearchParams;
@@ -24,10 +24,19 @@ pub struct GetAuthorSearch(AuthorSearchEndpoint);
 impl GetAuthorSearch {
     pub fn new(query_params: AuthorSearchParams) -> GetAuthorSearch {
         let endpoint = author_search_endpoint();
-        GetAuthorSearch(BaseEndpoint { query_params, endpoint })
+        GetAuthorSearch(BaseEndpoint {
+            query_params,
+            endpoint,
+            name: "author_search",
+            id: None,
+            timeout: None,
+            deadline: None,
+        })
     }
 }
 
+impl_timeouts!(GetAuthorSearch);
+
 #[cfg(feature = "blocking")]
 mod blocking {
     use super::*;
@@ -1,11 +1,13 @@
 use std::marker::PhantomData;
+use std::time::Instant;
 
 use crate::endpoint::{Endpoint, EndpointResult};
+use crate::trace::Trace;
 use crate::v1::definition::{Batch, Batched, SearchBatch};
 use crate::v1::pagination::{Page, Paged, Results};
 
 #[cfg(feature = "async")]
-pub(in crate::v1) use r#async::{BatchEndpointAsyncIter, SearchBatchEndpointAsyncIter};
+pub(in crate::v1) use r#async::{BatchEndpointAsyncIter, Next, SearchBatchEndpointAsyncIter};
 
 #[cfg(feature = "blocking")]
 pub(in crate::v1) use blocking::{BatchEndpointIter, SearchBatchEndpointIter};
@@ -17,6 +19,8 @@ struct InnerEndpointIter<'c, T, E, C, B> {
     batch: B,
     results: Results,
     count: u64,
+    // Set when a page fetch failed after the endpoint deadline.
+    expired: bool,
     // `batch` holds elements of type `T`.
     _marker: PhantomData<T>,
 }
@@ -71,7 +75,22 @@ impl<T, E: Paged, C, B: Batched<T>> InnerEndpointIter<'_, T, E, C, B> {
 impl<'c, T, E: Paged, C, B: Batched<T>> InnerEndpointIter<'c, T, E, C, B> {
     fn new(endpoint: E, mut batch: B, results: Results, client: &'c C) -> Self {
         batch.set_next(Some(endpoint.get_offset()));
-        Self { endpoint, results, client, batch, _marker: PhantomData, count: 0 }
+        Self { endpoint, results, client, batch, _marker: PhantomData, count: 0, expired: false }
+    }
+}
+
+impl<T, E: Endpoint + Paged, C, B> InnerEndpointIter<'_, T, E, C, B> {
+    #[inline]
+    fn page_trace(&self) -> Trace {
+        Trace::page(&self.endpoint, self.endpoint.get_offset(), self.endpoint.get_limit())
+    }
+
+    /// Checks whether the endpoint deadline has been reached, after a failed page fetch.
+    /// The error is still returned, but the iteration stops after it.
+    #[inline]
+    fn check_deadline(&mut self) {
+        self.expired =
+            self.endpoint.deadline().map_or(false, |deadline| Instant::now() >= deadline);
     }
 }
 
@@ -93,13 +112,24 @@ mod blocking {
         // see fit, like short-circuiting it by collecting into a Result.
         fn next(&mut self) -> Option<Self::Item> {
             if self.batch.as_ref().is_empty() {
+                // Stop iterating after a deadline was reached.
+                if self.expired {
+                    return None;
+                }
                 // Check requested results limit and then move to the next page.
                 self.next_page()?;
                 // Query the endpoint.
-                match self.endpoint.query(self.client) {
-                    Err(err) => return Some(Err(err)),
+                let trace = self.page_trace();
+                match trace.in_scope(|| self.endpoint.query(self.client)) {
+                    Err(err) => {
+                        self.check_deadline();
+                        return Some(Err(err));
+                    }
                     // Update current page results and control data.
-                    Ok(batch) => self.update_current_page(batch),
+                    Ok(batch) => {
+                        trace.record_results(batch.len());
+                        self.update_current_page(batch)
+                    }
                 };
                 // Reverse the results to `pop` in FIFO order.
                 self.batch.as_mut().reverse();
@@ -192,13 +222,24 @@ mod r#async {
         // see fit, like short-circuiting it by collecting into a Result.
         async fn next_async(&mut self) -> Option<EndpointResult<T, E, C>> {
             if self.batch.as_ref().is_empty() {
+                // Stop iterating after a deadline was reached.
+                if self.expired {
+                    return None;
+                }
                 // Check requested results limit and move to the next page.
                 self.next_page()?;
                 // Query the endpoint.
-                match self.endpoint.query_async(self.client).await {
-                    Err(err) => return Some(Err(err)),
+                let trace = self.page_trace();
+                match trace.instrument(self.endpoint.query_async(self.client)).await {
+                    Err(err) => {
+                        self.check_deadline();
+                        return Some(Err(err));
+                    }
                     // Update current page results and control data.
-                    Ok(batch) => self.update_current_page(batch),
+                    Ok(batch) => {
+                        trace.record_results(batch.len());
+                        self.update_current_page(batch)
+                    }
                 };
                 // Reverse the results to `pop` in FIFO order.
                 self.batch.as_mut().reverse();
@@ -208,6 +249,17 @@ mod r#async {
         }
     }
 
+    /// Resolves to the next item of an [`Unpin`] stream.
+    pub(in crate::v1) struct Next<'a, S>(pub(in crate::v1) &'a mut S);
+
+    impl<S: Stream + Unpin> Future for Next<'_, S> {
+        type Output = Option<S::Item>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            Pin::new(&mut *self.0).poll_next(cx)
+        }
+    }
+
     type PinnedBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
     type FutureOutput<'c, T, E, C, B> =
         Option<(EndpointResult<T, E, C>, InnerEndpointIter<'c, T, E, C, B>)>;
@@ -353,3 +405,56 @@ mod r#async {
         }
     }
 }
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use bytes::Bytes;
+    use http::{request::Builder, Response};
+    use url::{ParseError, Url};
+
+    use crate::client::{BaseClient, Client, RequestTimeout};
+    use crate::error::ApiError;
+    use crate::v1::definition::Citation;
+    use crate::v1::endpoint::GetPaperCitations;
+    use crate::v1::pagination::{Page, Results};
+    use crate::v1::parameter::PaperField;
+    use crate::v1::query_params::PaperCitationsParams;
+
+    /// A client that takes 20ms to return a page with one citation and a next page.
+    struct Slow;
+
+    impl BaseClient for Slow {
+        type Error = http::Error;
+
+        fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError> {
+            Url::parse(endpoint)
+        }
+    }
+
+    impl Client for Slow {
+        fn send(&self, request: Builder, _: Vec<u8>) -> Result<Response<Bytes>, http::Error> {
+            let timeout = request.extensions_ref().and_then(|ext| ext.get::<RequestTimeout>());
+            assert!(timeout.map_or(false, |timeout| timeout.0 <= Duration::from_secs(1)));
+            thread::sleep(Duration::from_millis(20));
+            let body = br#"{"offset": 0, "next": 1, "data": [{"citingPaper": {"paperId": "p2"}}]}"#;
+            Ok(Response::new(Bytes::from_static(body)))
+        }
+    }
+
+    #[test]
+    fn iteration_stops_at_deadline() {
+        let params = PaperCitationsParams::new(Some([PaperField::Contexts]), Page::default());
+        let endpoint = GetPaperCitations::new(params.unwrap(), "p1".to_owned())
+            .with_timeout(Duration::from_secs(1))
+            .with_deadline(Instant::now() + Duration::from_millis(10));
+        let mut citations = endpoint.paged::<Citation, _>(Results::All, &Slow);
+
+        // The first page was fetched before the deadline.
+        assert!(citations.next().unwrap().is_ok());
+        assert!(matches!(citations.next(), Some(Err(ApiError::Timeout))));
+        assert!(citations.next().is_none());
+    }
+}
@@ -1,4 +1,4 @@
-mod iter;
+pub(in crate::v1) mod iter;
 
 mod author;
 pub use author::*;
@@ -6,6 +6,8 @@ pub use author::*;
 mod paper;
 pub use paper::*;
 
+use std::time::{Duration, Instant};
+
 use http::Method;
 use serde::Serialize;
 use serde_urlencoded::ser::Error as UrlEncodedError;
@@ -19,6 +21,11 @@ use crate::v1::pagination::{Page, Paged};
 pub(in crate::v1) struct BaseEndpoint<P> {
     query_params: P,
     endpoint: String,
+    name: &'static str,
+    // The paper or author ID, if the endpoint is queried for one.
+    id: Option<String>,
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
 }
 
 impl<P: Serialize> Endpoint for BaseEndpoint<P> {
@@ -34,11 +41,71 @@ impl<P: Serialize> Endpoint for BaseEndpoint<P> {
         &self.endpoint
     }
 
+    #[inline]
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline]
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    #[inline]
+    fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
     fn query_params(&self) -> Result<UrlEncodedQuery<'_>, UrlEncodedError> {
         UrlEncodedQuery::with(&self.query_params)
     }
 }
 
+/// Endpoints with a request timeout and a deadline, for helpers
+/// that apply the same timeouts to different endpoints.
+pub(in crate::v1) trait Timeouts {
+    fn set_timeouts(&mut self, timeout: Option<Duration>, deadline: Option<Instant>);
+}
+
+/// Implements the timeout and deadline setters of an endpoint wrapping a [`BaseEndpoint`].
+macro_rules! impl_timeouts {
+    ($t:ident) => {
+        impl $t {
+            /// Sets the timeout of every request sent by the query or paged iteration.
+            pub fn with_timeout(mut self, timeout: std::time::Duration) -> $t {
+                self.0.timeout = Some(timeout);
+                self
+            }
+
+            /// Sets the instant after which the query or paged iteration stops sending
+            /// requests and returns [`ApiError::Timeout`](crate::error::ApiError::Timeout).
+            /// The request in flight is also timed out at the deadline, if the client
+            /// applies [`RequestTimeout`](crate::client::RequestTimeout).
+            pub fn with_deadline(mut self, deadline: std::time::Instant) -> $t {
+                self.0.deadline = Some(deadline);
+                self
+            }
+        }
+
+        impl crate::v1::endpoint::Timeouts for $t {
+            fn set_timeouts(
+                &mut self,
+                timeout: Option<std::time::Duration>,
+                deadline: Option<std::time::Instant>,
+            ) {
+                self.0.timeout = timeout;
+                self.0.deadline = deadline;
+            }
+        }
+    };
+}
+pub(in crate::v1) use impl_timeouts;
+
 impl<P: Paged> AsRef<Page> for BaseEndpoint<P> {
     fn as_ref(&self) -> &Page {
         self.query_params.as_ref()
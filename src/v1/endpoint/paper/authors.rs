@@ -3,7 +3,7 @@ use serde::de::DeserializeOwned;
 use crate::client::BaseClient;
 use crate::error::ApiError;
 use crate::v1::definition::AuthorWithPapers;
-use crate::v1::endpoint::{iter::BatchEndpointIter, BaseEndpoint};
+use crate::v1::endpoint::{impl_timeouts, iter::BatchEndpointIter, BaseEndpoint};
 use crate::v1::error::ResponseError;
 use crate::v1::pagination::Results;
 use crate::v1::query_params::PaperAuthorsParams;
@@ -24,10 +24,19 @@ pub struct GetPaperAuthors(PaperAuthorsEndpoint);
 impl GetPaperAuthors {
     pub fn new(query_params: PaperAuthorsParams, paper_id: String) -> GetPaperAuthors {
         let endpoint = paper_authors_endpoint(&paper_id);
-        Self(BaseEndpoint { query_params, endpoint })
+        Self(BaseEndpoint {
+            query_params,
+            endpoint,
+            name: "paper_authors",
+            id: Some(paper_id),
+            timeout: None,
+            deadline: None,
+        })
     }
 }
 
+impl_timeouts!(GetPaperAuthors);
+
 #[cfg(feature = "blocking")]
 mod blocking {
     use super::*;
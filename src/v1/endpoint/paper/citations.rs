@@ -3,7 +3,7 @@ use serde::de::DeserializeOwned;
 use crate::client::BaseClient;
 use crate::error::ApiError;
 use crate::v1::definition::Citation;
-use crate::v1::endpoint::{iter::BatchEndpointIter, BaseEndpoint};
+use crate::v1::endpoint::{impl_timeouts, iter::BatchEndpointIter, BaseEndpoint};
 use crate::v1::error::ResponseError;
 use crate::v1::pagination::Results;
 use crate::v1::query_params::PaperCitationsParams;
@@ -24,10 +24,19 @@ pub struct GetPaperCitations(PaperCitationsEndpoint);
 impl GetPaperCitations {
     pub fn new(query_params: PaperCitationsParams, paper_id: String) -> GetPaperCitations {
         let endpoint = paper_citations_endpoint(&paper_id);
-        GetPaperCitations(BaseEndpoint { query_params, endpoint })
+        GetPaperCitations(BaseEndpoint {
+            query_params,
+            endpoint,
+            name: "paper_citations",
+            id: Some(paper_id),
+            timeout: None,
+            deadline: None,
+        })
     }
 }
 
+impl_timeouts!(GetPaperCitations);
+
 #[cfg(feature = "blocking")]
 mod blocking {
     use super::*;
@@ -11,8 +11,8 @@ use serde::de::DeserializeOwned;
 
 use crate::client::BaseClient;
 use crate::error::ApiError;
-use crate::v1::definition::FullPaper;
-use crate::v1::endpoint::BaseEndpoint;
+use crate::v1::definition::{FullPaper, WithFields};
+use crate::v1::endpoint::{impl_timeouts, BaseEndpoint};
 use crate::v1::error::ResponseError;
 use crate::v1::query_params::PaperParams;
 use crate::v1::static_url::paper_endpoint;
@@ -26,10 +26,19 @@ pub struct GetPaper(PaperEndpoint);
 impl GetPaper {
     pub fn new(query_params: PaperParams, paper_id: String) -> GetPaper {
         let endpoint = paper_endpoint(&paper_id);
-        GetPaper(BaseEndpoint { query_params, endpoint })
+        GetPaper(BaseEndpoint {
+            query_params,
+            endpoint,
+            name: "paper",
+            id: Some(paper_id),
+            timeout: None,
+            deadline: None,
+        })
     }
 }
 
+impl_timeouts!(GetPaper);
+
 #[cfg(feature = "blocking")]
 mod blocking {
     use super::*;
@@ -44,6 +53,17 @@ mod blocking {
         {
             self.0.query(client).map(From::from)
         }
+
+        /// Like [`query`](Self::query), also returning the requested fields.
+        pub fn query_with_fields<T, C>(&self, client: &C) -> Result<WithFields<T>, PaperError<C>>
+        where
+            T: From<FullPaper> + DeserializeOwned,
+            C: Client,
+            PaperError<C>: From<C::Error>,
+        {
+            let fields = self.0.query_params.requested();
+            self.query(client).map(|value| WithFields::new(value, fields))
+        }
     }
 }
 
@@ -61,5 +81,19 @@ mod r#async {
         {
             self.0.query_async(client).await.map(From::from)
         }
+
+        /// Like [`query_async`](Self::query_async), also returning the requested fields.
+        pub async fn query_with_fields_async<T, C>(
+            &self,
+            client: &C,
+        ) -> Result<WithFields<T>, PaperError<C>>
+        where
+            T: From<FullPaper> + DeserializeOwned,
+            C: AsyncClient + Sync,
+            PaperError<C>: From<C::Error>,
+        {
+            let fields = self.0.query_params.requested();
+            self.query_async(client).await.map(|value| WithFields::new(value, fields))
+        }
     }
 }
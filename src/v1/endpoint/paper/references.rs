@@ -3,7 +3,7 @@ use serde::de::DeserializeOwned;
 use crate::client::BaseClient;
 use crate::error::ApiError;
 use crate::v1::definition::Reference;
-use crate::v1::endpoint::{iter::BatchEndpointIter, BaseEndpoint};
+use crate::v1::endpoint::{impl_timeouts, iter::BatchEndpointIter, BaseEndpoint};
 use crate::v1::error::ResponseError;
 use crate::v1::pagination::Results;
 use crate::v1::query_params::PaperReferencesParams;
@@ -24,10 +24,19 @@ pub struct GetPaperReferences(PaperReferencesEndpoint);
 impl GetPaperReferences {
     pub fn new(query_params: PaperReferencesParams, paper_id: String) -> GetPaperReferences {
         let endpoint = paper_references_endpoint(&paper_id);
-        GetPaperReferences(BaseEndpoint { query_params, endpoint })
+        GetPaperReferences(BaseEndpoint {
+            query_params,
+            endpoint,
+            name: "paper_references",
+            id: Some(paper_id),
+            timeout: None,
+            deadline: None,
+        })
     }
 }
 
+impl_timeouts!(GetPaperReferences);
+
 #[cfg(feature = "blocking")]
 mod blocking {
     use super::*;
@@ -3,7 +3,7 @@ use serde::de::DeserializeOwned;
 use crate::client::BaseClient;
 use crate::error::ApiError;
 use crate::v1::definition::BasePaper;
-use crate::v1::endpoint::{iter::SearchBatchEndpointIter, BaseEndpoint};
+use crate::v1::endpoint::{impl_timeouts, iter::SearchBatchEndpointIter, BaseEndpoint};
 use crate::v1::error::ResponseError;
 use crate::v1::pagination::Results;
 use crate::v1::query_params::PaperSearchParams;
@@ -24,10 +24,19 @@ pub struct GetPaperSearch(PaperSearchEndpoint);
 impl GetPaperSearch {
     pub fn new(query_params: PaperSearchParams) -> GetPaperSearch {
         let endpoint = paper_search_endpoint();
-        GetPaperSearch(BaseEndpoint { query_params, endpoint })
+        GetPaperSearch(BaseEndpoint {
+            query_params,
+            endpoint,
+            name: "paper_search",
+            id: None,
+            timeout: None,
+            deadline: None,
+        })
     }
 }
 
+impl_timeouts!(GetPaperSearch);
+
 #[cfg(feature = "blocking")]
 mod blocking {
     use super::*;
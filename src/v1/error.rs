@@ -1,6 +1,8 @@
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::v1::definition::EmbeddingModel;
+
 #[derive(Clone, Debug, Deserialize, Eq, Error, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[error("{error}")]
@@ -23,3 +25,49 @@ pub enum ResponseError {
     #[error(transparent)]
     Server(#[from] ServerError),
 }
+
+/// A field list that the endpoint would reject, see [`crate::v1::query_params`].
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum FieldsError {
+    /// Fields that can't be requested together.
+    #[error("the `{field}` and `{other}` fields can't be requested together from the {endpoint} endpoint")]
+    Exclusive { endpoint: &'static str, field: String, other: String },
+}
+
+/// A field name that could not be parsed, see the [`FromStr`](std::str::FromStr)
+/// implementations of the [`crate::v1::parameter`] field types.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum ParseFieldError {
+    /// The field is not known, or not accepted by this field type.
+    #[error("unknown field `{0}`")]
+    Unknown(String),
+
+    /// The field is known, but it doesn't accept this sub-field.
+    #[error("`{sub_field}` is not a valid sub-field of `{field}`")]
+    SubField { field: String, sub_field: String },
+}
+
+/// A vector whose dimension doesn't match the other vectors of an
+/// [`EmbeddingIndex`](crate::v1::similarity::EmbeddingIndex).
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[error("expected a vector of dimension {expected}, but found {found}")]
+pub struct DimensionError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+/// A paper embedding that can't be added to an
+/// [`EmbeddingIndex`](crate::v1::similarity::EmbeddingIndex).
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum IndexError {
+    /// The embedding vector doesn't have the dimension of the indexed vectors.
+    #[error(transparent)]
+    Dimension(#[from] DimensionError),
+
+    /// The embedding was produced by another model than the indexed embeddings.
+    #[error("expected an embedding of the {expected} model, but found {found}")]
+    Model { expected: EmbeddingModel, found: EmbeddingModel },
+}
@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::io;
+
+use crate::v1::definition::{BasePaper, FullPaper, PaperWithLinks};
+
+/// Paper data shared by the bibliographic export formats.
+pub trait Bibliographic {
+    /// The paper metadata.
+    fn base(&self) -> &BasePaper;
+
+    /// The authors' names, in citation order if it is known.
+    fn author_names(&self) -> Vec<&str>;
+
+    /// The first author's name, if the authors' order is known.
+    fn first_author(&self) -> Option<&str> {
+        self.author_names().first().copied()
+    }
+
+    /// The journal name, falling back to the venue, if not blank.
+    fn venue(&self) -> Option<&str> {
+        let base = self.base();
+        let journal = base.journal.as_ref().and_then(|journal| journal.name.as_deref());
+        journal.or(base.info.venue.as_deref()).filter(|venue| !venue.trim().is_empty())
+    }
+}
+
+impl Bibliographic for BasePaper {
+    /// The authors of a [`BasePaper`] are kept in a set, so their order is
+    /// unknown. They are sorted by name to get a stable output.
+    fn author_names(&self) -> Vec<&str> {
+        let authors = self.info.authors.iter().flatten();
+        let mut names: Vec<_> = authors.filter_map(|author| author.name.as_deref()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Unknown, since the authors are sorted by name.
+    fn first_author(&self) -> Option<&str> {
+        None
+    }
+
+    fn base(&self) -> &BasePaper {
+        self
+    }
+}
+
+impl Bibliographic for PaperWithLinks {
+    /// Authors are kept in the order returned by the API.
+    fn author_names(&self) -> Vec<&str> {
+        self.authors.iter().filter_map(|author| author.name.as_deref()).collect()
+    }
+
+    fn base(&self) -> &BasePaper {
+        &self.base
+    }
+}
+
+impl Bibliographic for FullPaper {
+    /// Authors are kept in the order returned by the API.
+    fn author_names(&self) -> Vec<&str> {
+        self.authors.iter().filter_map(|author| author.info.name.as_deref()).collect()
+    }
+
+    fn base(&self) -> &BasePaper {
+        &self.base
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ExportError<E: Error> {
+    /// Writing the exported data failed.
+    #[error("failed to write exported data: {source}")]
+    Io {
+        #[from]
+        source: io::Error,
+    },
+
+    /// The source of the exported papers returned an error.
+    #[error("failed to fetch exported data: {source}")]
+    Source { source: E },
+}
+
+/// A writer of papers into a bibliographic format.
+pub trait BibliographyWriter {
+    /// Writes a single paper entry.
+    fn write_paper<P: Bibliographic>(&mut self, paper: &P) -> io::Result<()>;
+
+    /// Writes every paper of `papers`, returning the number of written entries.
+    fn write_papers<'a, P, I>(&mut self, papers: I) -> io::Result<usize>
+    where
+        P: Bibliographic + 'a,
+        I: IntoIterator<Item = &'a P>,
+    {
+        let mut count = 0;
+        for paper in papers {
+            self.write_paper(paper)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Writes every paper yielded by a fallible iterator, such as the ones returned
+    /// by the paged endpoints, stopping at the first error. The entries written
+    /// before the error are kept, so the output is only truncated.
+    fn write_results<P, E, I>(&mut self, results: I) -> Result<usize, ExportError<E>>
+    where
+        P: Bibliographic,
+        E: Error,
+        I: IntoIterator<Item = Result<P, E>>,
+    {
+        let mut count = 0;
+        for result in results {
+            let paper = result.map_err(|source| ExportError::Source { source })?;
+            self.write_paper(&paper)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Writes every paper yielded by a fallible stream, such as the ones returned by
+/// the async paged endpoints, stopping at the first error.
+/// See [`BibliographyWriter::write_results`].
+#[cfg(feature = "async")]
+pub async fn write_stream<B, P, E, S>(
+    writer: &mut B,
+    mut stream: S,
+) -> Result<usize, ExportError<E>>
+where
+    B: BibliographyWriter,
+    P: Bibliographic,
+    E: Error,
+    S: futures_core::Stream<Item = Result<P, E>> + Unpin,
+{
+    use crate::v1::endpoint::iter::Next;
+
+    let mut count = 0;
+    while let Some(result) = Next(&mut stream).await {
+        let paper = result.map_err(|source| ExportError::Source { source })?;
+        writer.write_paper(&paper)?;
+        count += 1;
+    }
+    Ok(count)
+}
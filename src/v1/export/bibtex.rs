@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Write};
+
+use super::bibliography::{Bibliographic, BibliographyWriter};
+use crate::v1::definition::BasePaper;
+use crate::v1::text::fold;
+
+/// A single BibTeX entry, see [`ToBibtex`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BibtexEntry {
+    /// The entry type, such as `article` or `misc`.
+    pub entry_type: &'static str,
+    /// The citation key.
+    pub key: String,
+    /// Field names and their values, already escaped for LaTeX,
+    /// except for the DOI and URL that are verbatim.
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Display for BibtexEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "@{}{{{},", self.entry_type, self.key)?;
+        for (name, value) in &self.fields {
+            writeln!(f, "  {} = {{{}}},", name, value)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// Conversion of a paper definition into a [`BibtexEntry`].
+///
+/// Missing data is left out of the entry. The journal name, volume and pages
+/// are taken from the `journal` field, falling back to the `venue` for the
+/// name, and the year and month from the publication date. Conference papers,
+/// by their publication types, are `inproceedings` entries with the venue as
+/// `booktitle`, other papers with a venue are `article` entries with the venue
+/// as `journal`. The citation key is built from the first author's last name,
+/// if the authors' order is known, the year and the first word of the title
+/// that is not a stop word, like `vaswani2017attention`.
+pub trait ToBibtex {
+    fn to_bibtex(&self) -> BibtexEntry;
+}
+
+impl<P: Bibliographic> ToBibtex for P {
+    fn to_bibtex(&self) -> BibtexEntry {
+        entry(self.base(), &self.author_names(), self.first_author(), self.venue())
+    }
+}
+
+/// A [`BibliographyWriter`] for BibTeX entries.
+///
+/// A letter suffix is appended to repeated citation keys,
+/// so `smith2020deep` is followed by `smith2020deepb`.
+#[derive(Debug)]
+pub struct BibtexWriter<W> {
+    writer: W,
+    keys: HashMap<String, usize>,
+    written: usize,
+}
+
+impl<W: Write> BibtexWriter<W> {
+    pub fn new(writer: W) -> BibtexWriter<W> {
+        BibtexWriter { writer, keys: HashMap::new(), written: 0 }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> BibliographyWriter for BibtexWriter<W> {
+    fn write_paper<P: Bibliographic>(&mut self, paper: &P) -> io::Result<()> {
+        let mut entry = paper.to_bibtex();
+        let count = self.keys.entry(entry.key.clone()).or_insert(0);
+        if *count > 0 {
+            entry.key.push_str(&key_suffix(*count));
+        }
+        *count += 1;
+        // Separate entries by an empty line.
+        if self.written > 0 {
+            writeln!(self.writer)?;
+        }
+        self.written += 1;
+        write!(self.writer, "{}", entry)
+    }
+}
+
+/// Writes the BibTeX entries of `papers`, in order, see [`BibtexWriter`].
+pub fn write_bibtex<'a, P, W>(papers: impl IntoIterator<Item = &'a P>, writer: W) -> io::Result<()>
+where
+    P: Bibliographic + 'a,
+    W: Write,
+{
+    BibtexWriter::new(writer).write_papers(papers).map(drop)
+}
+
+/// Suffixes for repeated keys: `b`, `c`, ..., `z`, `ba`, ...
+fn key_suffix(mut count: usize) -> String {
+    let mut suffix = Vec::new();
+    while count > 0 {
+        suffix.push(b'a' + (count % 26) as u8);
+        count /= 26;
+    }
+    suffix.reverse();
+    String::from_utf8(suffix).expect("suffix must be ASCII")
+}
+
+fn entry(
+    paper: &BasePaper,
+    authors: &[&str],
+    first_author: Option<&str>,
+    venue: Option<&str>,
+) -> BibtexEntry {
+    let info = &paper.info;
+    let external_ids = paper.external_ids.as_ref();
+    let doi = external_ids.and_then(|ids| ids.doi.as_deref());
+    let arxiv = external_ids.and_then(|ids| ids.ar_xiv.as_deref());
+    let journal = paper.journal.as_ref();
+    let date = paper.date();
+    let year = date.map(|date| u64::from(date.year()));
+
+    let mut fields = Vec::new();
+    if let Some(ref title) = info.title {
+        fields.push(("title", escape_latex(title)));
+    }
+    if !authors.is_empty() {
+        let authors: Vec<_> = authors.iter().map(|name| escape_latex(name)).collect();
+        fields.push(("author", authors.join(" and ")));
+    }
+    if let Some(date) = date {
+        fields.push(("year", date.year().to_string()));
+        fields.extend(date.month().map(|month| ("month", month.to_string())));
+    }
+    let conference = is_conference(paper);
+    if let Some(venue) = venue {
+        fields.push((if conference { "booktitle" } else { "journal" }, escape_latex(venue)));
+    }
+    if let Some(volume) = journal.and_then(|journal| journal.volume.as_deref()) {
+        fields.push(("volume", escape_latex(volume.trim())));
+    }
+    if let Some(pages) = journal.and_then(|journal| journal.pages.as_deref()) {
+        // BibTeX page ranges use an en dash.
+        let pages: Vec<_> = pages.split('-').map(str::trim).collect();
+        fields.push(("pages", escape_latex(&pages.join("--"))));
+    }
+    if let Some(doi) = doi {
+        fields.push(("doi", escape_braces(doi)));
+    }
+    if let Some(arxiv) = arxiv {
+        fields.push(("eprint", escape_latex(arxiv)));
+        fields.push(("archiveprefix", "arXiv".to_owned()));
+    }
+    if let Some(ref url) = info.url {
+        fields.push(("url", escape_braces(url)));
+    }
+
+    let entry_type = match venue {
+        Some(_) if conference => "inproceedings",
+        Some(_) => "article",
+        None => "misc",
+    };
+    let key = citation_key(first_author, year, info.title.as_deref())
+        .or_else(|| info.paper_id.clone())
+        .unwrap_or_else(|| "unknown".to_owned());
+
+    BibtexEntry { entry_type, key, fields }
+}
+
+/// Whether `paper` was published in conference proceedings. Papers typed as both
+/// conference and journal papers are told apart by their publication venue.
+fn is_conference(paper: &BasePaper) -> bool {
+    let types = paper.publication_types.iter().flatten();
+    let (mut conference, mut journal) = (false, false);
+    for r#type in types {
+        conference |= r#type == "Conference";
+        journal |= r#type == "JournalArticle";
+    }
+    let venue = paper.publication_venue.as_ref().and_then(|venue| venue.r#type.as_deref());
+    conference
+        && (!journal || venue.map_or(false, |venue| venue.eq_ignore_ascii_case("conference")))
+}
+
+const STOP_WORDS: [&str; 12] =
+    ["a", "an", "and", "for", "from", "in", "of", "on", "the", "to", "towards", "with"];
+
+/// Lowercase ASCII alphanumeric characters of `word`, without the accent of the
+/// common latin letters, so `Müller` is `muller`. Other characters are dropped,
+/// and a word without any ASCII character is left out of the key.
+fn key_part(word: &str) -> String {
+    word.chars().flat_map(fold).filter(char::is_ascii_alphanumeric).collect()
+}
+
+fn citation_key(
+    first_author: Option<&str>,
+    year: Option<u64>,
+    title: Option<&str>,
+) -> Option<String> {
+    let last_name = first_author.and_then(|name| name.split_whitespace().last()).map(key_part);
+    let year = year.map(|year| year.to_string());
+    let word = title.and_then(|title| {
+        title
+            .split_whitespace()
+            .map(key_part)
+            .find(|w| !w.is_empty() && !STOP_WORDS.contains(&&**w))
+    });
+
+    let key: String = [last_name, year, word].iter().flatten().map(String::as_str).collect();
+    if key.is_empty() {
+        None
+    } else {
+        Some(key)
+    }
+}
+
+/// Escapes the braces of `text`, for verbatim values like DOIs and URLs
+/// where other LaTeX special characters, such as `_` or `%`, are kept as is.
+fn escape_braces(text: &str) -> String {
+    text.replace('{', r"\{").replace('}', r"\}")
+}
+
+/// Escapes the LaTeX special characters of `text`.
+pub fn escape_latex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str(r"\textasciitilde{}"),
+            '^' => escaped.push_str(r"\textasciicircum{}"),
+            '\\' => escaped.push_str(r"\textbackslash{}"),
+            // Line breaks are not allowed inside of a field value.
+            '\n' | '\r' => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::{
+        Author, AuthorInfo, FullPaper, Journal, PaperExternalId, PaperWithLinks,
+    };
+
+    fn paper() -> FullPaper {
+        let mut paper = FullPaper::default();
+        paper.base.info.title = Some("The Attention Is All You Need".to_owned());
+        paper.base.info.year = Some(2017);
+        paper.base.info.venue = Some("NeurIPS".to_owned());
+        paper.base.journal = Some(Journal {
+            name: None,
+            volume: Some("30".to_owned()),
+            pages: Some("5998-6008".to_owned()),
+        });
+        paper.base.external_ids = Some(PaperExternalId {
+            doi: Some("10.1000/x_y".to_owned()),
+            ..PaperExternalId::default()
+        });
+        paper.authors = ["Ashish Vaswani", "Noam Shazeer"]
+            .iter()
+            .map(|name| Author {
+                info: AuthorInfo { author_id: None, name: Some((*name).to_owned()) },
+                ..Author::default()
+            })
+            .collect();
+        paper
+    }
+
+    #[test]
+    fn citation_key_skips_stop_words() {
+        assert_eq!(paper().to_bibtex().key, "vaswani2017attention");
+    }
+
+    #[test]
+    fn citation_key_falls_back_to_paper_id() {
+        let mut paper = BasePaper::default();
+        paper.info.paper_id = Some("abc".to_owned());
+        assert_eq!(paper.to_bibtex().key, "abc");
+        assert_eq!(paper.to_bibtex().entry_type, "misc");
+    }
+
+    #[test]
+    fn citation_key_uses_the_first_author_in_order() {
+        let authors = ["Noam Shazeer", "Ashish Vaswani"];
+        let mut paper = PaperWithLinks::default();
+        paper.base.info.year = Some(2017);
+        paper.authors = authors
+            .iter()
+            .map(|name| AuthorInfo { author_id: None, name: Some((*name).to_owned()) })
+            .collect();
+        assert_eq!(paper.to_bibtex().key, "shazeer2017");
+
+        // The authors of a base paper are unordered, so none of them is first.
+        let mut base = paper.base.clone();
+        base.info.authors = Some(paper.authors.iter().cloned().collect());
+        assert_eq!(base.to_bibtex().key, "2017");
+        let author = ("author", "Ashish Vaswani and Noam Shazeer".to_owned());
+        assert!(base.to_bibtex().fields.contains(&author));
+    }
+
+    #[test]
+    fn citation_key_transliterates_accents() {
+        assert_eq!(
+            citation_key(Some("Jürgen Müller"), Some(2020), Some("Über")),
+            Some("muller2020uber".into())
+        );
+        // Names without latin letters are left out.
+        assert_eq!(
+            citation_key(Some("李明"), Some(2020), Some("Deep nets")),
+            Some("2020deep".into())
+        );
+    }
+
+    #[test]
+    fn latex_special_characters_are_escaped() {
+        let expected = r"50\% of R\&D\_\{x\} \textasciitilde{} \textbackslash{} \textasciicircum{}";
+        assert_eq!(escape_latex(r"50% of R&D_{x} ~ \ ^"), expected);
+    }
+
+    #[test]
+    fn conference_papers_are_inproceedings() {
+        let mut paper = paper();
+        paper.base.publication_types = Some(vec!["Conference".to_owned()]);
+        let entry = paper.to_bibtex();
+        assert_eq!(entry.entry_type, "inproceedings");
+        assert!(entry.fields.contains(&("booktitle", "NeurIPS".to_owned())));
+
+        paper.base.publication_types = Some(vec!["JournalArticle".to_owned()]);
+        let entry = paper.to_bibtex();
+        assert_eq!(entry.entry_type, "article");
+        assert!(entry.fields.contains(&("journal", "NeurIPS".to_owned())));
+    }
+
+    #[test]
+    fn repeated_keys_get_a_suffix() {
+        let papers = vec![paper(), paper()];
+        let mut output = Vec::new();
+        write_bibtex(&papers, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.starts_with("@article{vaswani2017attention,\n"));
+        assert!(output.contains("@article{vaswani2017attentionb,\n"));
+        assert!(output.contains("  author = {Ashish Vaswani and Noam Shazeer},\n"));
+        assert!(output.contains("  doi = {10.1000/x_y},\n"));
+        assert!(
+            output.contains("  journal = {NeurIPS},\n  volume = {30},\n  pages = {5998--6008},\n")
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::io::{self, Write};
+
+use serde_json::{json, Map, Value};
+
+use super::bibliography::{Bibliographic, BibliographyWriter};
+
+/// A [`BibliographyWriter`] for a CSL-JSON array of items,
+/// as imported by Zotero, Mendeley and citeproc processors.
+///
+/// Items are streamed into the array as they are written,
+/// so [`CslJsonWriter::finish`] must be called to close it.
+#[derive(Debug)]
+pub struct CslJsonWriter<W: Write> {
+    writer: W,
+    written: usize,
+}
+
+impl<W: Write> CslJsonWriter<W> {
+    pub fn new(writer: W) -> CslJsonWriter<W> {
+        CslJsonWriter { writer, written: 0 }
+    }
+
+    /// Closes the CSL-JSON array and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.written == 0 {
+            self.writer.write_all(b"[")?;
+        }
+        self.writer.write_all(b"\n]\n")?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> BibliographyWriter for CslJsonWriter<W> {
+    fn write_paper<P: Bibliographic>(&mut self, paper: &P) -> io::Result<()> {
+        let separator: &[u8] = if self.written == 0 { b"[\n  " } else { b",\n  " };
+        self.writer.write_all(separator)?;
+        serde_json::to_writer(&mut self.writer, &csl_item(paper))?;
+        self.written += 1;
+        Ok(())
+    }
+}
+
+/// Writes `papers` as a CSL-JSON array, see [`CslJsonWriter`].
+pub fn write_csl_json<'a, P, W>(
+    papers: impl IntoIterator<Item = &'a P>,
+    writer: W,
+) -> io::Result<()>
+where
+    P: Bibliographic + 'a,
+    W: Write,
+{
+    let mut writer = CslJsonWriter::new(writer);
+    writer.write_papers(papers)?;
+    writer.finish().map(drop)
+}
+
+/// Builds a CSL-JSON item, keyed by the Semantic Scholar paper ID.
+pub fn csl_item<P: Bibliographic>(paper: &P) -> Value {
+    let base = paper.base();
+    let info = &base.info;
+    let ids = base.external_ids.as_ref();
+    let journal = base.journal.as_ref();
+    let venue = paper.venue();
+
+    let mut item = Map::new();
+    if let Some(ref paper_id) = info.paper_id {
+        item.insert("id".into(), json!(paper_id));
+    }
+    let item_type = if venue.is_some() { "article-journal" } else { "article" };
+    item.insert("type".into(), json!(item_type));
+    if let Some(ref title) = info.title {
+        item.insert("title".into(), json!(title));
+    }
+    let authors: Vec<_> = paper.author_names().into_iter().map(csl_name).collect();
+    if !authors.is_empty() {
+        item.insert("author".into(), Value::Array(authors));
+    }
+    if let Some(date) = base.date() {
+        let (month, day) = (date.month().map(u16::from), date.day().map(u16::from));
+        let parts: Vec<u16> = Some(date.year()).into_iter().chain(month).chain(day).collect();
+        item.insert("issued".into(), json!({ "date-parts": [parts] }));
+    }
+    if let Some(venue) = venue {
+        item.insert("container-title".into(), json!(venue));
+    }
+    if let Some(volume) = journal.and_then(|journal| journal.volume.as_deref()) {
+        item.insert("volume".into(), json!(volume.trim()));
+    }
+    if let Some(pages) = journal.and_then(|journal| journal.pages.as_deref()) {
+        item.insert("page".into(), json!(pages.trim()));
+    }
+    if let Some(ref r#abstract) = base.r#abstract {
+        item.insert("abstract".into(), json!(r#abstract));
+    }
+    if let Some(ref url) = info.url {
+        item.insert("URL".into(), json!(url));
+    }
+    if let Some(ids) = ids {
+        if let Some(ref doi) = ids.doi {
+            item.insert("DOI".into(), json!(doi));
+        }
+        if let Some(ref pmid) = ids.pub_med {
+            item.insert("PMID".into(), json!(pmid));
+        }
+        if let Some(ref pmcid) = ids.pub_med_central {
+            item.insert("PMCID".into(), json!(pmcid));
+        }
+        // CSL has no ArXiv variable, the archive ones are what Zotero maps it to.
+        if let Some(ref arxiv) = ids.ar_xiv {
+            item.insert("archive".into(), json!("arXiv"));
+            item.insert("archive_location".into(), json!(arxiv));
+        }
+    }
+    Value::Object(item)
+}
+
+/// Splits a name at its last word, the API does not tell given and family names apart.
+fn csl_name(name: &str) -> Value {
+    match name.trim().rsplit_once(' ') {
+        Some((given, family)) => json!({ "family": family, "given": given.trim() }),
+        None => json!({ "literal": name.trim() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::{BasePaper, PaperExternalId};
+
+    #[test]
+    fn csl_json_array_is_valid_json() {
+        let mut paper = BasePaper::default();
+        paper.info.paper_id = Some("abc".to_owned());
+        paper.info.year = Some(2017);
+        paper.info.authors = Some(
+            [("1", "Ashish Vaswani"), ("2", "Plato")]
+                .iter()
+                .map(|(id, name)| crate::v1::definition::AuthorInfo {
+                    author_id: Some((*id).to_owned()),
+                    name: Some((*name).to_owned()),
+                })
+                .collect(),
+        );
+        paper.external_ids =
+            Some(PaperExternalId { ar_xiv: Some("1706.03762".to_owned()), ..Default::default() });
+
+        let mut output = Vec::new();
+        write_csl_json(&[paper.clone(), paper], &mut output).unwrap();
+        let items: Value = serde_json::from_slice(&output).unwrap();
+
+        let expected = json!({
+            "id": "abc",
+            "type": "article",
+            "author": [{ "family": "Vaswani", "given": "Ashish" }, { "literal": "Plato" }],
+            "issued": { "date-parts": [[2017]] },
+            "archive": "arXiv",
+            "archive_location": "1706.03762",
+        });
+        assert_eq!(items, json!([expected.clone(), expected]));
+    }
+
+    #[test]
+    fn empty_csl_json_array_is_valid_json() {
+        let mut output = Vec::new();
+        write_csl_json::<BasePaper, _>(&[], &mut output).unwrap();
+        assert_eq!(serde_json::from_slice::<Value>(&output).unwrap(), json!([]));
+    }
+}
@@ -0,0 +1,70 @@
+use std::io::{self, Write};
+
+use super::graph::{AttrValue, Attributes, Graph};
+
+/// Writes `graph` as a Graphviz DOT digraph.
+pub fn write_dot<W: Write>(graph: &Graph, mut writer: W) -> io::Result<()> {
+    writeln!(writer, "digraph scholars {{")?;
+    for node in graph.node_entries() {
+        writeln!(writer, "  {} [{}];", quote(&node.id), attribute_list(&node.attributes))?;
+    }
+    for edge in graph.edge_entries() {
+        let (source, target) = (quote(&edge.source), quote(&edge.target));
+        writeln!(writer, "  {} -> {} [{}];", source, target, attribute_list(&edge.attributes))?;
+    }
+    writeln!(writer, "}}")
+}
+
+/// Quotes a DOT identifier, escaping any inner quotes and backslashes.
+fn quote(id: &str) -> String {
+    let mut quoted = String::with_capacity(id.len() + 2);
+    quoted.push('"');
+    for c in id.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' | '\r' => quoted.push(' '),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn attribute_list(attributes: &Attributes<'_>) -> String {
+    let attributes = attributes.iter().map(|(name, value)| {
+        let value = match *value {
+            AttrValue::String(s) => quote(s),
+            AttrValue::Integer(n) => n.to_string(),
+            AttrValue::Boolean(b) => b.to_string(),
+        };
+        format!("{}={}", name, value)
+    });
+    attributes.collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::BasePaper;
+
+    #[test]
+    fn dot_quotes_identifiers_and_labels() {
+        let mut graph = Graph::new();
+        let mut paper = BasePaper::default();
+        paper.info.paper_id = Some("a".to_owned());
+        paper.info.title = Some(r#"The "best" \ paper"#.to_owned());
+        paper.citation_count = Some(3);
+        graph.add_paper(paper);
+
+        let mut output = Vec::new();
+        write_dot(&graph, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        let expected =
+            r#"  "paper:a" [kind="paper", label="The \"best\" \\ paper", citation_count=3];"#;
+        assert!(output.contains(expected), "{}", output);
+    }
+}
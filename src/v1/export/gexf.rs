@@ -0,0 +1,100 @@
+use std::io::{self, Write};
+
+use super::escape_xml;
+use super::graph::{AttrType, AttrValue, Attributes, Graph, EDGE_ATTRIBUTES, NODE_ATTRIBUTES};
+
+/// Writes `graph` as a directed GEXF 1.3 document.
+///
+/// Node labels are taken from the paper title and author name.
+pub fn write_gexf<W: Write>(graph: &Graph, mut writer: W) -> io::Result<()> {
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(writer, r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#)?;
+    writeln!(writer, r#"  <graph mode="static" defaultedgetype="directed">"#)?;
+
+    write_attributes(&mut writer, "node", &NODE_ATTRIBUTES)?;
+    write_attributes(&mut writer, "edge", &EDGE_ATTRIBUTES)?;
+
+    writeln!(writer, "    <nodes>")?;
+    for node in graph.node_entries() {
+        let (id, label) = (escape_xml(&node.id), escape_xml(node.label.unwrap_or(&node.id)));
+        writeln!(writer, r#"      <node id="{}" label="{}">"#, id, label)?;
+        write_attvalues(&mut writer, &NODE_ATTRIBUTES, &node.attributes)?;
+        writeln!(writer, "      </node>")?;
+    }
+    writeln!(writer, "    </nodes>")?;
+
+    writeln!(writer, "    <edges>")?;
+    for (id, edge) in graph.edge_entries().enumerate() {
+        let (source, target) = (escape_xml(&edge.source), escape_xml(&edge.target));
+        writeln!(writer, r#"      <edge id="{}" source="{}" target="{}">"#, id, source, target)?;
+        write_attvalues(&mut writer, &EDGE_ATTRIBUTES, &edge.attributes)?;
+        writeln!(writer, "      </edge>")?;
+    }
+    writeln!(writer, "    </edges>")?;
+
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</gexf>")
+}
+
+fn write_attributes<W: Write>(
+    writer: &mut W,
+    class: &str,
+    declared: &[(&str, AttrType)],
+) -> io::Result<()> {
+    writeln!(writer, r#"    <attributes class="{}">"#, class)?;
+    for (id, (name, ty)) in declared.iter().enumerate() {
+        let ty = match ty {
+            AttrType::String => "string",
+            AttrType::Integer => "long",
+            AttrType::Boolean => "boolean",
+        };
+        writeln!(writer, r#"      <attribute id="{}" title="{}" type="{}"/>"#, id, name, ty)?;
+    }
+    writeln!(writer, "    </attributes>")
+}
+
+/// GEXF refers to the attributes by their declaration index.
+fn write_attvalues<W: Write>(
+    writer: &mut W,
+    declared: &[(&str, AttrType)],
+    attributes: &Attributes<'_>,
+) -> io::Result<()> {
+    writeln!(writer, "        <attvalues>")?;
+    for (name, value) in attributes {
+        let id = declared.iter().position(|(declared, _)| declared == name);
+        let id = id.expect("exported attributes must be declared");
+        let value = match *value {
+            AttrValue::String(s) => escape_xml(s),
+            AttrValue::Integer(n) => n.to_string(),
+            AttrValue::Boolean(b) => b.to_string(),
+        };
+        writeln!(writer, r#"          <attvalue for="{}" value="{}"/>"#, id, value)?;
+    }
+    writeln!(writer, "        </attvalues>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::{AuthorInfo, BasePaper};
+
+    #[test]
+    fn gexf_links_authors_to_papers() {
+        let mut graph = Graph::new();
+        let mut paper = BasePaper::default();
+        paper.info.paper_id = Some("a".to_owned());
+        paper.info.year = Some(2020);
+        let author = AuthorInfo { author_id: Some("1".to_owned()), name: Some("Ada".to_owned()) };
+        paper.info.authors = Some(std::iter::once(author).collect());
+        graph.add_paper(paper);
+
+        let mut output = Vec::new();
+        write_gexf(&graph, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains(r#"<node id="paper:a" label="paper:a">"#));
+        assert!(output.contains(r#"<node id="author:1" label="Ada">"#));
+        assert!(output.contains(r#"<attvalue for="2" value="2020"/>"#));
+        assert!(output.contains(r#"<edge id="0" source="author:1" target="paper:a">"#));
+    }
+}
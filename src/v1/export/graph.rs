@@ -0,0 +1,264 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use crate::v1::definition::{Author, AuthorInfo, BasePaper};
+
+/// A directed citation link between two papers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CitationEdge {
+    /// ID of the paper that cites.
+    pub citing: String,
+    /// ID of the paper that is cited.
+    pub cited: String,
+    // See: https://www.semanticscholar.org/faq#influential-citations.
+    pub is_influential: Option<bool>,
+}
+
+/// A directed link from an author to one of their papers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorshipEdge {
+    pub author: String,
+    pub paper: String,
+}
+
+/// A graph of papers and authors, linked by citation and authorship edges.
+///
+/// Nodes are keyed by their Semantic Scholar ID, so papers and authors
+/// without an ID are ignored. Adding an already known node keeps the
+/// first one, and duplicated edges are ignored.
+///
+/// Edges may refer to papers or authors that were never added, like a cited
+/// paper only known by its ID. The exporters write a stub node, with only its
+/// kind, for each of them, so that every edge has both of its ends.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Graph {
+    papers: BTreeMap<String, BasePaper>,
+    authors: BTreeMap<String, Author>,
+    citations: Vec<CitationEdge>,
+    authorships: Vec<AuthorshipEdge>,
+    links: HashSet<(bool, String, String)>,
+}
+
+impl Graph {
+    pub fn new() -> Graph {
+        Graph::default()
+    }
+
+    /// Adds a paper node, and an author node and authorship
+    /// edge for every identified author listed in the paper.
+    pub fn add_paper(&mut self, paper: BasePaper) {
+        let paper_id = match paper.info.paper_id {
+            Some(ref id) => id.clone(),
+            None => return,
+        };
+        if let Some(ref authors) = paper.info.authors {
+            for author in authors {
+                if let Some(ref author_id) = author.author_id {
+                    self.add_author_info(author.clone());
+                    self.add_authorship(author_id.clone(), paper_id.clone());
+                }
+            }
+        }
+        self.papers.entry(paper_id).or_insert(paper);
+    }
+
+    pub fn add_author(&mut self, author: Author) {
+        if let Some(ref id) = author.info.author_id {
+            self.authors.entry(id.clone()).or_insert(author);
+        }
+    }
+
+    pub fn add_author_info(&mut self, author: AuthorInfo) {
+        self.add_author(Author { info: author, ..Author::default() })
+    }
+
+    pub fn add_citation(&mut self, edge: CitationEdge) {
+        if self.links.insert((true, edge.citing.clone(), edge.cited.clone())) {
+            self.citations.push(edge);
+        }
+    }
+
+    pub fn add_authorship(&mut self, author: String, paper: String) {
+        if self.links.insert((false, author.clone(), paper.clone())) {
+            self.authorships.push(AuthorshipEdge { author, paper });
+        }
+    }
+
+    /// Paper nodes, ordered by ID.
+    pub fn papers(&self) -> impl Iterator<Item = (&str, &BasePaper)> {
+        self.papers.iter().map(|(id, paper)| (id.as_str(), paper))
+    }
+
+    /// Author nodes, ordered by ID.
+    pub fn authors(&self) -> impl Iterator<Item = (&str, &Author)> {
+        self.authors.iter().map(|(id, author)| (id.as_str(), author))
+    }
+
+    /// Citation edges, in insertion order.
+    pub fn citations(&self) -> impl Iterator<Item = &CitationEdge> {
+        self.citations.iter()
+    }
+
+    /// Authorship edges, in insertion order.
+    pub fn authorships(&self) -> impl Iterator<Item = &AuthorshipEdge> {
+        self.authorships.iter()
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+mod crawl {
+    use super::*;
+    use crate::v1::crawl::{CrawlEdge, CrawlEvent};
+
+    impl From<CrawlEdge> for CitationEdge {
+        fn from(edge: CrawlEdge) -> CitationEdge {
+            CitationEdge {
+                citing: edge.citing,
+                cited: edge.cited,
+                is_influential: edge.is_influential,
+            }
+        }
+    }
+
+    impl Extend<CrawlEvent> for Graph {
+        fn extend<I: IntoIterator<Item = CrawlEvent>>(&mut self, events: I) {
+            for event in events {
+                match event {
+                    CrawlEvent::Node(node) => self.add_paper(node.paper),
+                    CrawlEvent::Edge(edge) => self.add_citation(edge.into()),
+                }
+            }
+        }
+    }
+
+    impl FromIterator<CrawlEvent> for Graph {
+        fn from_iter<I: IntoIterator<Item = CrawlEvent>>(events: I) -> Graph {
+            let mut graph = Graph::new();
+            graph.extend(events);
+            graph
+        }
+    }
+}
+
+/// Node identifiers shared by all formats, papers and authors
+/// IDs are prefixed since they are not unique across both sets.
+pub(super) fn paper_node_id(id: &str) -> String {
+    format!("paper:{}", id)
+}
+
+pub(super) fn author_node_id(id: &str) -> String {
+    format!("author:{}", id)
+}
+
+/// The type of a node or edge attribute.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum AttrType {
+    String,
+    Integer,
+    Boolean,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum AttrValue<'a> {
+    String(&'a str),
+    Integer(u64),
+    Boolean(bool),
+}
+
+/// Attributes that nodes may hold, papers and authors share the same set.
+pub(super) const NODE_ATTRIBUTES: [(&str, AttrType); 7] = [
+    ("kind", AttrType::String),
+    ("label", AttrType::String),
+    ("year", AttrType::Integer),
+    ("venue", AttrType::String),
+    ("citation_count", AttrType::Integer),
+    ("paper_count", AttrType::Integer),
+    ("h_index", AttrType::Integer),
+];
+
+/// Attributes that edges may hold.
+pub(super) const EDGE_ATTRIBUTES: [(&str, AttrType); 2] =
+    [("kind", AttrType::String), ("is_influential", AttrType::Boolean)];
+
+pub(super) type Attributes<'a> = Vec<(&'static str, AttrValue<'a>)>;
+
+/// A node as written by the exporters.
+pub(super) struct NodeEntry<'a> {
+    pub(super) id: String,
+    pub(super) label: Option<&'a str>,
+    pub(super) attributes: Attributes<'a>,
+}
+
+/// An edge as written by the exporters.
+pub(super) struct EdgeEntry<'a> {
+    pub(super) source: String,
+    pub(super) target: String,
+    pub(super) attributes: Attributes<'a>,
+}
+
+impl Graph {
+    pub(super) fn node_entries(&self) -> impl Iterator<Item = NodeEntry<'_>> {
+        let papers = self.papers().map(|(id, paper)| {
+            let info = &paper.info;
+            let label = info.title.as_deref();
+            let mut attributes = vec![("kind", AttrValue::String("paper"))];
+            attributes.extend(label.map(|title| ("label", AttrValue::String(title))));
+            attributes.extend(info.year.map(|year| ("year", AttrValue::Integer(year))));
+            attributes
+                .extend(info.venue.as_deref().map(|venue| ("venue", AttrValue::String(venue))));
+            attributes
+                .extend(paper.citation_count.map(|n| ("citation_count", AttrValue::Integer(n))));
+            NodeEntry { id: paper_node_id(id), label, attributes }
+        });
+
+        let authors = self.authors().map(|(id, author)| {
+            let label = author.info.name.as_deref();
+            let mut attributes = vec![("kind", AttrValue::String("author"))];
+            attributes.extend(label.map(|name| ("label", AttrValue::String(name))));
+            attributes
+                .extend(author.citation_count.map(|n| ("citation_count", AttrValue::Integer(n))));
+            attributes.extend(author.paper_count.map(|n| ("paper_count", AttrValue::Integer(n))));
+            attributes.extend(author.h_index.map(|n| ("h_index", AttrValue::Integer(n))));
+            NodeEntry { id: author_node_id(id), label, attributes }
+        });
+
+        // Stubs for the edge ends that are not nodes of the graph.
+        let mut stubs = BTreeSet::new();
+        for edge in self.citations() {
+            stubs.extend([&edge.citing, &edge.cited].iter().map(|id| ("paper", id.as_str())));
+        }
+        for edge in self.authorships() {
+            stubs.insert(("author", edge.author.as_str()));
+            stubs.insert(("paper", edge.paper.as_str()));
+        }
+        stubs.retain(|(kind, id)| match *kind {
+            "paper" => !self.papers.contains_key(*id),
+            _ => !self.authors.contains_key(*id),
+        });
+        let stubs = stubs.into_iter().map(|(kind, id)| {
+            let id = if kind == "paper" { paper_node_id(id) } else { author_node_id(id) };
+            NodeEntry { id, label: None, attributes: vec![("kind", AttrValue::String(kind))] }
+        });
+
+        papers.chain(authors).chain(stubs)
+    }
+
+    pub(super) fn edge_entries(&self) -> impl Iterator<Item = EdgeEntry<'_>> {
+        let citations = self.citations().map(|edge| {
+            let mut attributes = vec![("kind", AttrValue::String("cites"))];
+            attributes
+                .extend(edge.is_influential.map(|b| ("is_influential", AttrValue::Boolean(b))));
+            let source = paper_node_id(&edge.citing);
+            let target = paper_node_id(&edge.cited);
+            EdgeEntry { source, target, attributes }
+        });
+
+        let authorships = self.authorships().map(|edge| {
+            let attributes = vec![("kind", AttrValue::String("authored"))];
+            let source = author_node_id(&edge.author);
+            let target = paper_node_id(&edge.paper);
+            EdgeEntry { source, target, attributes }
+        });
+
+        citations.chain(authorships)
+    }
+}
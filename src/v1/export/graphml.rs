@@ -0,0 +1,101 @@
+use std::io::{self, Write};
+
+use super::escape_xml;
+use super::graph::{AttrType, AttrValue, Attributes, Graph, EDGE_ATTRIBUTES, NODE_ATTRIBUTES};
+
+/// Writes `graph` as a directed GraphML document.
+///
+/// Node and edge attributes are declared as GraphML keys, prefixed
+/// by `n_` and `e_` respectively, since both sets share some names.
+pub fn write_graphml<W: Write>(graph: &Graph, mut writer: W) -> io::Result<()> {
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(writer, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+
+    for (name, ty) in NODE_ATTRIBUTES {
+        write_key(&mut writer, "n", "node", name, ty)?;
+    }
+    for (name, ty) in EDGE_ATTRIBUTES {
+        write_key(&mut writer, "e", "edge", name, ty)?;
+    }
+
+    writeln!(writer, r#"  <graph id="G" edgedefault="directed">"#)?;
+    for node in graph.node_entries() {
+        writeln!(writer, r#"    <node id="{}">"#, escape_xml(&node.id))?;
+        write_data(&mut writer, "n", &node.attributes)?;
+        writeln!(writer, "    </node>")?;
+    }
+    for edge in graph.edge_entries() {
+        let (source, target) = (escape_xml(&edge.source), escape_xml(&edge.target));
+        writeln!(writer, r#"    <edge source="{}" target="{}">"#, source, target)?;
+        write_data(&mut writer, "e", &edge.attributes)?;
+        writeln!(writer, "    </edge>")?;
+    }
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")
+}
+
+fn write_key<W: Write>(
+    writer: &mut W,
+    prefix: &str,
+    domain: &str,
+    name: &str,
+    ty: AttrType,
+) -> io::Result<()> {
+    let ty = match ty {
+        AttrType::String => "string",
+        AttrType::Integer => "long",
+        AttrType::Boolean => "boolean",
+    };
+    writeln!(
+        writer,
+        r#"  <key id="{}_{}" for="{}" attr.name="{}" attr.type="{}"/>"#,
+        prefix, name, domain, name, ty
+    )
+}
+
+fn write_data<W: Write>(
+    writer: &mut W,
+    prefix: &str,
+    attributes: &Attributes<'_>,
+) -> io::Result<()> {
+    for (name, value) in attributes {
+        let value = match *value {
+            AttrValue::String(s) => escape_xml(s),
+            AttrValue::Integer(n) => n.to_string(),
+            AttrValue::Boolean(b) => b.to_string(),
+        };
+        writeln!(writer, r#"      <data key="{}_{}">{}</data>"#, prefix, name, value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::BasePaper;
+    use crate::v1::export::CitationEdge;
+
+    #[test]
+    fn graphml_escapes_attribute_values() {
+        let mut graph = Graph::new();
+        let mut paper = BasePaper::default();
+        paper.info.paper_id = Some("a".to_owned());
+        paper.info.title = Some("Rock & <Roll>".to_owned());
+        graph.add_paper(paper);
+        graph.add_citation(CitationEdge {
+            citing: "a".to_owned(),
+            cited: "b".to_owned(),
+            is_influential: Some(true),
+        });
+
+        let mut output = Vec::new();
+        write_graphml(&graph, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains(r#"<data key="n_label">Rock &amp; &lt;Roll&gt;</data>"#));
+        assert!(output.contains(r#"<edge source="paper:a" target="paper:b">"#));
+        // The cited paper was never added, but it has a stub node.
+        assert!(output.contains(r#"<node id="paper:b">"#));
+        assert!(output.contains(r#"<data key="e_is_influential">true</data>"#));
+    }
+}
@@ -0,0 +1,41 @@
+mod bibliography;
+pub use bibliography::*;
+
+mod bibtex;
+pub use bibtex::*;
+
+mod csl;
+pub use csl::*;
+
+mod dot;
+pub use dot::*;
+
+mod gexf;
+pub use gexf::*;
+
+mod graph;
+pub use graph::*;
+
+mod graphml;
+pub use graphml::*;
+
+mod ris;
+pub use ris::*;
+
+/// Escapes the XML special characters of `text`.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters are not allowed in XML 1.0 documents.
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
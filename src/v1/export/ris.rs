@@ -0,0 +1,135 @@
+use std::io::{self, Write};
+
+use super::bibliography::{Bibliographic, BibliographyWriter};
+
+/// A [`BibliographyWriter`] for RIS records, as imported by Zotero, Mendeley and EndNote.
+///
+/// PubMed and ArXiv identifiers are written as `AN` (accession number)
+/// tags, prefixed by `PMID:` and `arXiv:` respectively.
+#[derive(Debug)]
+pub struct RisWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> RisWriter<W> {
+    pub fn new(writer: W) -> RisWriter<W> {
+        RisWriter { writer }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn tag(&mut self, tag: &str, value: &str) -> io::Result<()> {
+        // A tag value must fit in a single line.
+        let value = value.replace(['\n', '\r'], " ");
+        write!(self.writer, "{}  - {}\r\n", tag, value.trim())
+    }
+}
+
+impl<W: Write> BibliographyWriter for RisWriter<W> {
+    fn write_paper<P: Bibliographic>(&mut self, paper: &P) -> io::Result<()> {
+        let base = paper.base();
+        let info = &base.info;
+        let ids = base.external_ids.as_ref();
+        let journal = base.journal.as_ref();
+        let venue = paper.venue();
+
+        self.tag("TY", if venue.is_some() { "JOUR" } else { "GEN" })?;
+        if let Some(ref title) = info.title {
+            self.tag("TI", title)?;
+        }
+        for author in paper.author_names() {
+            self.tag("AU", author)?;
+        }
+        if let Some(date) = base.date() {
+            self.tag("PY", &date.year().to_string())?;
+            // Dates are written as `YYYY/MM/DD/`, with empty unknown parts.
+            if let Some(month) = date.month() {
+                let day = date.day().map(|day| format!("{:02}", day)).unwrap_or_default();
+                self.tag("DA", &format!("{:04}/{:02}/{}/", date.year(), month, day))?;
+            }
+        }
+        if let Some(venue) = venue {
+            self.tag("JO", venue)?;
+        }
+        if let Some(volume) = journal.and_then(|journal| journal.volume.as_deref()) {
+            self.tag("VL", volume)?;
+        }
+        if let Some(pages) = journal.and_then(|journal| journal.pages.as_deref()) {
+            let (start, end) = pages.split_once('-').unwrap_or((pages, ""));
+            self.tag("SP", start)?;
+            if !end.trim().is_empty() {
+                self.tag("EP", end)?;
+            }
+        }
+        if let Some(ref r#abstract) = base.r#abstract {
+            self.tag("AB", r#abstract)?;
+        }
+        if let Some(doi) = ids.and_then(|ids| ids.doi.as_deref()) {
+            self.tag("DO", doi)?;
+        }
+        if let Some(pmid) = ids.and_then(|ids| ids.pub_med.as_deref()) {
+            self.tag("AN", &format!("PMID:{}", pmid))?;
+        }
+        if let Some(arxiv) = ids.and_then(|ids| ids.ar_xiv.as_deref()) {
+            self.tag("AN", &format!("arXiv:{}", arxiv))?;
+        }
+        if let Some(ref url) = info.url {
+            self.tag("UR", url)?;
+        }
+        if let Some(ref paper_id) = info.paper_id {
+            self.tag("ID", paper_id)?;
+        }
+        self.tag("ER", "")
+    }
+}
+
+/// Writes the RIS records of `papers`, in order, see [`RisWriter`].
+pub fn write_ris<'a, P, W>(papers: impl IntoIterator<Item = &'a P>, writer: W) -> io::Result<()>
+where
+    P: Bibliographic + 'a,
+    W: Write,
+{
+    RisWriter::new(writer).write_papers(papers).map(drop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::{BasePaper, PaperExternalId};
+
+    #[test]
+    fn ris_record_includes_external_ids() {
+        let mut paper = BasePaper::default();
+        paper.info.paper_id = Some("abc".to_owned());
+        paper.info.title = Some("A\ntitle".to_owned());
+        paper.external_ids = Some(PaperExternalId {
+            doi: Some("10.1/2".to_owned()),
+            pub_med: Some("123".to_owned()),
+            ar_xiv: Some("1706.03762".to_owned()),
+            ..PaperExternalId::default()
+        });
+
+        let mut output = Vec::new();
+        write_ris(&[paper], &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        let expected = "TY  - GEN\r\nTI  - A title\r\nDO  - 10.1/2\r\nAN  - PMID:123\r\n\
+                        AN  - arXiv:1706.03762\r\nID  - abc\r\nER  - \r\n";
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn ris_record_uses_the_publication_date() {
+        let mut paper = BasePaper::default();
+        paper.info.year = Some(2017);
+        paper.publication_date = "2017-06-12".parse().ok();
+
+        let mut output = Vec::new();
+        write_ris(&[paper], &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!(output, "TY  - GEN\r\nPY  - 2017\r\nDA  - 2017/06/12/\r\nER  - \r\n");
+    }
+}
@@ -4,9 +4,20 @@ mod static_url;
 #[cfg(any(feature = "blocking", feature = "async"))]
 pub mod endpoint;
 
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub mod crawl;
+
+pub mod coauthor;
 pub mod definition;
+pub mod disambiguation;
 pub mod error;
+pub mod export;
 pub mod pagination;
 pub mod parameter;
 pub mod query_params;
+pub mod similarity;
+pub mod store;
 pub mod utils;
+
+mod text;
+mod validation;
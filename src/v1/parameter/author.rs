@@ -1,10 +1,14 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
 
 use serde::Serialize;
+use serde_with::DeserializeFromStr;
 
 use super::paper::{BasePaperField, PaperInfoField};
+use super::{parse_sub_field, split_field};
+use crate::v1::error::ParseFieldError;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum AuthorInfoField {
     AuthorId,
@@ -20,7 +24,19 @@ impl Display for AuthorInfoField {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+impl FromStr for AuthorInfoField {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "authorId" => Ok(Self::AuthorId),
+            "name" => Ok(Self::Name),
+            _ => Err(ParseFieldError::Unknown(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum AuthorField {
     Info(AuthorInfoField),
@@ -50,16 +66,36 @@ impl Display for AuthorField {
     }
 }
 
+impl FromStr for AuthorField {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "externalIds" => Ok(Self::ExternalIds),
+            "url" => Ok(Self::Url),
+            "aliases" => Ok(Self::Aliases),
+            "affiliations" => Ok(Self::Affiliations),
+            "homepage" => Ok(Self::Homepage),
+            "paperCount" => Ok(Self::PaperCount),
+            "citationCount" => Ok(Self::CitationCount),
+            "hIndex" => Ok(Self::HIndex),
+            _ => s.parse().map(Self::Info),
+        }
+    }
+}
+
 impl From<AuthorInfoField> for AuthorField {
     fn from(field: AuthorInfoField) -> AuthorField {
         AuthorField::Info(field)
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum AuthorWithPapersField {
     Author(AuthorField),
+    // The API rejects `papers` without sub-fields, so `Papers(None)`
+    // requests `papers.title`, see its `Display` implementation.
     Papers(Option<BasePaperField>),
 }
 
@@ -84,6 +120,18 @@ impl Display for AuthorWithPapersField {
     }
 }
 
+impl FromStr for AuthorWithPapersField {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split_field(s) {
+            ("papers", None) => Ok(Self::Papers(None)),
+            ("papers", Some(sub)) => parse_sub_field("papers", sub).map(|f| Self::Papers(Some(f))),
+            _ => s.parse().map(Self::Author),
+        }
+    }
+}
+
 impl From<AuthorField> for AuthorWithPapersField {
     fn from(field: AuthorField) -> AuthorWithPapersField {
         AuthorWithPapersField::Author(field)
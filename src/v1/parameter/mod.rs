@@ -2,3 +2,93 @@ mod author;
 pub use author::*;
 mod paper;
 pub use paper::*;
+
+use std::str::FromStr;
+
+use crate::v1::error::ParseFieldError;
+
+#[cfg(feature = "derive")]
+pub use scholars_derive::SelectFields;
+
+/// A response type that selects the fields it is deserialized from.
+///
+/// With the `derive` feature, this can be derived for custom paper types.
+pub trait SelectFields<F> {
+    /// The fields to request for this type.
+    fn fields() -> Vec<F>;
+}
+
+/// Splits a dotted field name, like `authors.name`, into its top level field and sub-field.
+fn split_field(field: &str) -> (&str, Option<&str>) {
+    match field.split_once('.') {
+        Some((field, sub_field)) => (field, Some(sub_field)),
+        None => (field, None),
+    }
+}
+
+/// Parses the `sub_field` of `field`.
+fn parse_sub_field<F: FromStr>(field: &str, sub_field: &str) -> Result<F, ParseFieldError> {
+    sub_field.parse().map_err(|_| ParseFieldError::SubField {
+        field: field.to_owned(),
+        sub_field: sub_field.to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use super::*;
+    use crate::v1::utils::{
+        all_author_with_papers_fields, all_full_paper_fields, all_paper_fields,
+        all_paper_with_links_fields,
+    };
+
+    fn assert_round_trip<F>(fields: impl Iterator<Item = F>)
+    where
+        F: Display + FromStr<Err = ParseFieldError>,
+    {
+        for field in fields.map(|field| field.to_string()) {
+            assert_eq!(field.parse::<F>().map(|parsed| parsed.to_string()), Ok(field));
+        }
+    }
+
+    #[test]
+    fn parsed_fields_round_trip_with_display() {
+        assert_round_trip(all_full_paper_fields());
+        let versions = [EmbeddingVersion::SpecterV1, EmbeddingVersion::SpecterV2];
+        assert_round_trip(versions.into_iter().map(|v| FullPaperField::Embedding(Some(v))));
+        assert_round_trip(all_paper_with_links_fields());
+        assert_round_trip(all_paper_fields());
+        assert_round_trip(all_author_with_papers_fields());
+    }
+
+    #[test]
+    fn unknown_and_disallowed_sub_fields_are_rejected() {
+        let sub_field = |field: &str, sub_field: &str| ParseFieldError::SubField {
+            field: field.to_owned(),
+            sub_field: sub_field.to_owned(),
+        };
+        assert_eq!("foo".parse::<FullPaperField>(), Err(ParseFieldError::Unknown("foo".into())));
+        assert_eq!("citations.tldr".parse::<FullPaperField>(), Err(sub_field("citations", "tldr")));
+        assert_eq!(
+            "authors.hIndex".parse::<PaperWithLinksField>(),
+            Err(sub_field("authors", "hIndex"))
+        );
+        assert_eq!(
+            "papers.citations.title".parse::<AuthorWithPapersField>(),
+            Err(sub_field("papers", "citations.title"))
+        );
+
+        let fields: Vec<FullPaperField> =
+            serde_json::from_str(r#"["title", "authors.name", "citations.year"]"#).unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                FullPaperField::from(PaperInfoField::Title),
+                FullPaperField::from(AuthorInfoField::Name),
+                FullPaperField::citations_from(PaperInfoField::Year),
+            ]
+        );
+    }
+}
@@ -1,10 +1,14 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
 
 use serde::Serialize;
+use serde_with::DeserializeFromStr;
 
 use super::author::{AuthorField, AuthorInfoField};
+use super::{parse_sub_field, split_field};
+use crate::v1::error::ParseFieldError;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum PaperInfoField {
     PaperId,
@@ -28,7 +32,23 @@ impl Display for PaperInfoField {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+impl FromStr for PaperInfoField {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "paperId" => Ok(Self::PaperId),
+            "url" => Ok(Self::Url),
+            "title" => Ok(Self::Title),
+            "venue" => Ok(Self::Venue),
+            "year" => Ok(Self::Year),
+            "authors" => Ok(Self::Authors),
+            _ => Err(ParseFieldError::Unknown(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum BasePaperField {
     Info(PaperInfoField),
@@ -39,6 +59,13 @@ pub enum BasePaperField {
     InfluentialCitationCount,
     IsOpenAccess,
     FieldsOfStudy,
+    PublicationDate,
+    PublicationTypes,
+    Journal,
+    PublicationVenue,
+    OpenAccessPdf,
+    S2FieldsOfStudy,
+    CitationStyles,
 }
 
 impl Display for BasePaperField {
@@ -52,6 +79,37 @@ impl Display for BasePaperField {
             Self::InfluentialCitationCount => write!(f, "influentialCitationCount"),
             Self::IsOpenAccess => write!(f, "isOpenAccess"),
             Self::FieldsOfStudy => write!(f, "fieldsOfStudy"),
+            Self::PublicationDate => write!(f, "publicationDate"),
+            Self::PublicationTypes => write!(f, "publicationTypes"),
+            Self::Journal => write!(f, "journal"),
+            Self::PublicationVenue => write!(f, "publicationVenue"),
+            Self::OpenAccessPdf => write!(f, "openAccessPdf"),
+            Self::S2FieldsOfStudy => write!(f, "s2FieldsOfStudy"),
+            Self::CitationStyles => write!(f, "citationStyles"),
+        }
+    }
+}
+
+impl FromStr for BasePaperField {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "abstract" => Ok(Self::Abstract),
+            "externalIds" => Ok(Self::ExternalIds),
+            "referenceCount" => Ok(Self::ReferenceCount),
+            "citationCount" => Ok(Self::CitationCount),
+            "influentialCitationCount" => Ok(Self::InfluentialCitationCount),
+            "isOpenAccess" => Ok(Self::IsOpenAccess),
+            "fieldsOfStudy" => Ok(Self::FieldsOfStudy),
+            "publicationDate" => Ok(Self::PublicationDate),
+            "publicationTypes" => Ok(Self::PublicationTypes),
+            "journal" => Ok(Self::Journal),
+            "publicationVenue" => Ok(Self::PublicationVenue),
+            "openAccessPdf" => Ok(Self::OpenAccessPdf),
+            "s2FieldsOfStudy" => Ok(Self::S2FieldsOfStudy),
+            "citationStyles" => Ok(Self::CitationStyles),
+            _ => s.parse().map(Self::Info),
         }
     }
 }
@@ -62,7 +120,7 @@ impl From<PaperInfoField> for BasePaperField {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum PaperField {
     Base(BasePaperField),
@@ -82,6 +140,19 @@ impl Display for PaperField {
     }
 }
 
+impl FromStr for PaperField {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "contexts" => Ok(Self::Contexts),
+            "intents" => Ok(Self::Intents),
+            "isInfluential" => Ok(Self::IsInfluential),
+            _ => s.parse().map(Self::Base),
+        }
+    }
+}
+
 impl From<BasePaperField> for PaperField {
     fn from(field: BasePaperField) -> PaperField {
         PaperField::Base(field)
@@ -94,13 +165,14 @@ impl From<PaperInfoField> for PaperField {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum PaperWithLinksField {
     Base(BasePaperField),
+    // The API only returns the author ID and name for the authors of these papers.
     Authors(Option<AuthorInfoField>),
-    Citations(Option<PaperInfoField>),
-    References(Option<PaperInfoField>),
+    Citations(Option<BasePaperField>),
+    References(Option<BasePaperField>),
 }
 
 impl Display for PaperWithLinksField {
@@ -117,15 +189,37 @@ impl Display for PaperWithLinksField {
     }
 }
 
+impl FromStr for PaperWithLinksField {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split_field(s) {
+            ("authors", None) => Ok(Self::Authors(None)),
+            ("authors", Some(sub)) => {
+                parse_sub_field("authors", sub).map(|f| Self::Authors(Some(f)))
+            }
+            ("citations", None) => Ok(Self::Citations(None)),
+            ("citations", Some(sub)) => {
+                parse_sub_field("citations", sub).map(|f| Self::Citations(Some(f)))
+            }
+            ("references", None) => Ok(Self::References(None)),
+            ("references", Some(sub)) => {
+                parse_sub_field("references", sub).map(|f| Self::References(Some(f)))
+            }
+            _ => s.parse().map(Self::Base),
+        }
+    }
+}
+
 impl PaperWithLinksField {
     #[inline]
-    pub fn citations_from(field: PaperInfoField) -> PaperWithLinksField {
-        PaperWithLinksField::Citations(Some(field))
+    pub fn citations_from(field: impl Into<BasePaperField>) -> PaperWithLinksField {
+        PaperWithLinksField::Citations(Some(field.into()))
     }
 
     #[inline]
-    pub fn references_from(field: PaperInfoField) -> PaperWithLinksField {
-        PaperWithLinksField::References(Some(field))
+    pub fn references_from(field: impl Into<BasePaperField>) -> PaperWithLinksField {
+        PaperWithLinksField::References(Some(field.into()))
     }
 }
 
@@ -147,14 +241,44 @@ impl From<AuthorInfoField> for PaperWithLinksField {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+/// The model version of the embeddings requested with [`FullPaperField::Embedding`].
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmbeddingVersion {
+    SpecterV1,
+    SpecterV2,
+}
+
+impl Display for EmbeddingVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::SpecterV1 => write!(f, "specter_v1"),
+            Self::SpecterV2 => write!(f, "specter_v2"),
+        }
+    }
+}
+
+impl FromStr for EmbeddingVersion {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "specter_v1" => Ok(Self::SpecterV1),
+            "specter_v2" => Ok(Self::SpecterV2),
+            _ => Err(ParseFieldError::Unknown(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, DeserializeFromStr, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum FullPaperField {
     Base(BasePaperField),
     Authors(Option<AuthorField>),
-    Citations(Option<PaperInfoField>),
-    References(Option<PaperInfoField>),
-    Embedding,
+    Citations(Option<BasePaperField>),
+    References(Option<BasePaperField>),
+    // `None` requests the default model version, currently SPECTER v1.
+    Embedding(Option<EmbeddingVersion>),
     Tldr,
 }
 
@@ -168,21 +292,49 @@ impl Display for FullPaperField {
             Self::Citations(Some(field)) => write!(f, "citations.{}", field),
             Self::References(None) => write!(f, "references"),
             Self::References(Some(field)) => write!(f, "references.{}", field),
-            Self::Embedding => write!(f, "embedding"),
+            Self::Embedding(None) => write!(f, "embedding"),
+            Self::Embedding(Some(version)) => write!(f, "embedding.{}", version),
             Self::Tldr => write!(f, "tldr"),
         }
     }
 }
 
+impl FromStr for FullPaperField {
+    type Err = ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split_field(s) {
+            ("authors", None) => Ok(Self::Authors(None)),
+            ("authors", Some(sub)) => {
+                parse_sub_field("authors", sub).map(|f| Self::Authors(Some(f)))
+            }
+            ("citations", None) => Ok(Self::Citations(None)),
+            ("citations", Some(sub)) => {
+                parse_sub_field("citations", sub).map(|f| Self::Citations(Some(f)))
+            }
+            ("references", None) => Ok(Self::References(None)),
+            ("references", Some(sub)) => {
+                parse_sub_field("references", sub).map(|f| Self::References(Some(f)))
+            }
+            ("embedding", None) => Ok(Self::Embedding(None)),
+            ("embedding", Some(sub)) => {
+                parse_sub_field("embedding", sub).map(|v| Self::Embedding(Some(v)))
+            }
+            ("tldr", None) => Ok(Self::Tldr),
+            _ => s.parse().map(Self::Base),
+        }
+    }
+}
+
 impl FullPaperField {
     #[inline]
-    pub fn citations_from(field: PaperInfoField) -> FullPaperField {
-        FullPaperField::Citations(Some(field))
+    pub fn citations_from(field: impl Into<BasePaperField>) -> FullPaperField {
+        FullPaperField::Citations(Some(field.into()))
     }
 
     #[inline]
-    pub fn references_from(field: PaperInfoField) -> FullPaperField {
-        FullPaperField::References(Some(field))
+    pub fn references_from(field: impl Into<BasePaperField>) -> FullPaperField {
+        FullPaperField::References(Some(field.into()))
     }
 }
 
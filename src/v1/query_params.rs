@@ -7,10 +7,14 @@ use serde_with::{serde_as, skip_serializing_none, CommaSeparator, StringWithSepa
 
 use crate::serialize::as_non_empty_string;
 use crate::urlencoded::UrlEncodedQuery;
+use crate::v1::definition::RequestedFields;
+use crate::v1::error::FieldsError;
 use crate::v1::pagination::Page;
 use crate::v1::parameter::{
     AuthorWithPapersField, BasePaperField, FullPaperField, PaperField, PaperWithLinksField,
+    SelectFields,
 };
+use crate::v1::validation::{self, FieldRules};
 
 #[serde_as]
 #[skip_serializing_none]
@@ -28,12 +32,31 @@ impl<F> FieldsParam<F>
 where
     F: Clone + Debug + Display + Eq + Hash + PartialEq + Serialize,
 {
-    fn new<T>(fields: Option<impl IntoIterator<Item = T>>) -> FieldsParam<F>
+    fn new<T>(
+        rules: FieldRules,
+        fields: Option<impl IntoIterator<Item = T>>,
+    ) -> Result<FieldsParam<F>, FieldsError>
     where
         T: Into<F>,
     {
-        let fields = fields.map(|fields| fields.into_iter().map(Into::into).collect());
-        FieldsParam { fields }
+        let fields: Option<HashSet<F>> =
+            fields.map(|fields| fields.into_iter().map(Into::into).collect());
+        // An empty list requests the default fields, like `None`.
+        let fields = fields.filter(|fields| !fields.is_empty());
+        if let Some(ref fields) = fields {
+            rules.validate(fields.iter().map(ToString::to_string))?;
+        }
+        Ok(FieldsParam { fields })
+    }
+
+    /// The requested field names, always including the `id` field.
+    /// `defaults` are the fields returned when none are specified.
+    fn requested(&self, id: &str, defaults: &[&str]) -> RequestedFields {
+        let names: Vec<String> = match self.fields {
+            Some(ref fields) => fields.iter().map(ToString::to_string).collect(),
+            None => defaults.iter().map(|name| (*name).to_owned()).collect(),
+        };
+        RequestedFields::new(names.into_iter().chain(Some(id.to_owned())))
     }
 }
 
@@ -54,12 +77,16 @@ impl<F> SearchParams<F>
 where
     F: Clone + Debug + Display + Eq + Hash + PartialEq + Serialize,
 {
-    fn new<T>(query: String, fields: Option<impl IntoIterator<Item = T>>) -> SearchParams<F>
+    fn new<T>(
+        rules: FieldRules,
+        query: String,
+        fields: Option<impl IntoIterator<Item = T>>,
+    ) -> Result<SearchParams<F>, FieldsError>
     where
         T: Into<F>,
     {
-        let fields = FieldsParam::new(fields);
-        SearchParams { fields, query }
+        let fields = FieldsParam::new(rules, fields)?;
+        Ok(SearchParams { fields, query })
     }
 }
 
@@ -72,70 +99,116 @@ struct PagedParams<T> {
 }
 
 macro_rules! define_impl_params {
-    ( $($param:ident : $field:ty),* ) => {$(
+    ( $($param:ident : $field:ty => $rules:ident, $id:literal, $defaults:expr),* ) => {$(
         #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
         pub struct $param(FieldsParam<$field>);
 
         impl $param {
-            pub fn new<T>(fields: Option<impl IntoIterator<Item = T>>) -> $param
+            /// Fails if the endpoint would reject `fields`, see [`FieldsError`].
+            /// An empty `fields` list requests the default fields, like `None`.
+            pub fn new<T>(fields: Option<impl IntoIterator<Item = T>>) -> Result<$param, FieldsError>
             where
                 T: Into<$field>,
             {
-                Self(FieldsParam::new(fields))
+                FieldsParam::new(validation::$rules, fields).map(Self)
+            }
+
+            /// Requests the fields selected by `S`.
+            pub fn selecting<S: SelectFields<$field>>() -> Result<$param, FieldsError> {
+                Self::new(Some(S::fields()))
+            }
+
+            /// The fields requested with these parameters.
+            pub fn requested(&self) -> RequestedFields {
+                self.0.requested($id, &$defaults)
             }
         }
     )*};
 }
 
 define_impl_params! {
-    PaperParams : FullPaperField,
-    AuthorParams : AuthorWithPapersField
+    PaperParams : FullPaperField => PAPER, "paperId", ["title"],
+    AuthorParams : AuthorWithPapersField => AUTHOR, "authorId", ["name"]
 }
 
 macro_rules! define_impl_paged_params {
-    ( $($param:ident : $field:ty),* ) => {$(
+    ( $($param:ident : $field:ty => $rules:ident, $id:literal, $defaults:expr),* ) => {$(
         #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
         pub struct $param(PagedParams<FieldsParam<$field>>);
 
         impl $param {
-            pub fn new<T>(fields: Option<impl IntoIterator<Item = T>>, page: Page) -> $param
+            /// Fails if the endpoint would reject `fields`, see [`FieldsError`].
+            /// An empty `fields` list requests the default fields, like `None`.
+            pub fn new<T>(
+                fields: Option<impl IntoIterator<Item = T>>,
+                page: Page,
+            ) -> Result<$param, FieldsError>
             where
                 T: Into<$field>,
             {
-                let params = FieldsParam::new(fields);
-                Self(PagedParams { params, page })
+                let params = FieldsParam::new(validation::$rules, fields)?;
+                Ok(Self(PagedParams { params, page }))
+            }
+
+            /// Requests the fields selected by `S`.
+            pub fn selecting<S: SelectFields<$field>>(page: Page) -> Result<$param, FieldsError> {
+                Self::new(Some(S::fields()), page)
+            }
+
+            /// The fields requested with these parameters.
+            pub fn requested(&self) -> RequestedFields {
+                self.0.params.requested($id, &$defaults)
             }
         }
     )*};
 }
 
 define_impl_paged_params! {
-    AuthorPapersParams : PaperWithLinksField,
-    PaperAuthorsParams : AuthorWithPapersField,
-    PaperCitationsParams : PaperField,
-    PaperReferencesParams : PaperField
+    AuthorPapersParams : PaperWithLinksField => AUTHOR_PAPERS, "paperId", ["title"],
+    PaperAuthorsParams : AuthorWithPapersField => PAPER_AUTHORS, "authorId", ["name"],
+    PaperCitationsParams : PaperField => PAPER_CITATIONS, "paperId", ["title"],
+    PaperReferencesParams : PaperField => PAPER_REFERENCES, "paperId", ["title"]
 }
 
 macro_rules! define_impl_search_params {
-    ( $($param:ident : $field:ty),* ) => {$(
+    ( $($param:ident : $field:ty => $rules:ident, $id:literal, $defaults:expr),* ) => {$(
         #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
         pub struct $param(PagedParams<SearchParams<$field>>);
 
         impl $param {
-            pub fn new<T>(query: String, fields: Option<impl IntoIterator<Item = T>>, page: Page) -> $param
+            /// Fails if the endpoint would reject `fields`, see [`FieldsError`].
+            /// An empty `fields` list requests the default fields, like `None`.
+            pub fn new<T>(
+                query: String,
+                fields: Option<impl IntoIterator<Item = T>>,
+                page: Page,
+            ) -> Result<$param, FieldsError>
             where
                 T: Into<$field>,
             {
-                let params = SearchParams::new(query, fields);
-                Self(PagedParams { params, page })
+                let params = SearchParams::new(validation::$rules, query, fields)?;
+                Ok(Self(PagedParams { params, page }))
+            }
+
+            /// Requests the fields selected by `S`.
+            pub fn selecting<S: SelectFields<$field>>(
+                query: String,
+                page: Page,
+            ) -> Result<$param, FieldsError> {
+                Self::new(query, Some(S::fields()), page)
+            }
+
+            /// The fields requested with these parameters.
+            pub fn requested(&self) -> RequestedFields {
+                self.0.params.fields.requested($id, &$defaults)
             }
         }
     )*};
 }
 
 define_impl_search_params! {
-    PaperSearchParams : BasePaperField,
-    AuthorSearchParams : AuthorWithPapersField
+    PaperSearchParams : BasePaperField => PAPER_SEARCH, "paperId", ["title"],
+    AuthorSearchParams : AuthorWithPapersField => AUTHOR_SEARCH, "authorId", ["name"]
 }
 
 static EXPECT_MSG: &str = "must be serializable by `serde_urlencoded::Serialzer`";
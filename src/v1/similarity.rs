@@ -0,0 +1,277 @@
+//! Similarity of paper embeddings, and a nearest neighbors index over them.
+//!
+//! Embeddings are requested with [`FullPaperField::Embedding`] and
+//! stored as `f32`, like the vectors of a [`CompactEmbedding`]. Only embeddings
+//! of the same model can be compared, so all the papers of an index should be
+//! requested with the same [`EmbeddingVersion`].
+//!
+//! [`FullPaperField::Embedding`]: crate::v1::parameter::FullPaperField::Embedding
+//! [`EmbeddingVersion`]: crate::v1::parameter::EmbeddingVersion
+
+use crate::v1::definition::{CompactEmbedding, EmbeddingModel, FullPaper};
+use crate::v1::error::{DimensionError, IndexError};
+
+/// The dot product of `a` and `b`.
+///
+/// # Panics
+///
+/// If `a` and `b` have different lengths.
+pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len(), "vectors must have the same dimension");
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// The euclidean norm of `v`.
+pub fn norm(v: &[f32]) -> f32 {
+    dot(v, v).sqrt()
+}
+
+/// The cosine similarity of `a` and `b`, in `[-1, 1]`.
+/// It is 0 if either of them is a zero vector.
+///
+/// # Panics
+///
+/// If `a` and `b` have different lengths.
+pub fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 {
+        return 0.0;
+    }
+    dot(a, b) / norms
+}
+
+/// Scales `v` to a unit vector, leaving zero vectors unchanged.
+fn normalize(v: &mut [f32]) {
+    let norm = norm(v);
+    if norm > 0.0 {
+        v.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+/// A paper found by [`EmbeddingIndex::nearest`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Neighbor<'a> {
+    /// The paper ID.
+    pub id: &'a str,
+    /// The cosine similarity to the query.
+    pub similarity: f32,
+}
+
+/// An in-memory index of paper embeddings, searched by brute force
+/// for the nearest neighbors by cosine similarity.
+///
+/// Vectors are normalized when inserted and kept contiguously,
+/// so a search is a single pass of dot products over the index.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EmbeddingIndex {
+    // The vectors dimension, set by the first insertion.
+    dim: usize,
+    // The embeddings model, set by the first paper with a model.
+    model: Option<EmbeddingModel>,
+    ids: Vec<String>,
+    vectors: Vec<f32>,
+}
+
+impl EmbeddingIndex {
+    pub fn new() -> EmbeddingIndex {
+        EmbeddingIndex::default()
+    }
+
+    /// Indexes the embedding of every paper that has an ID and an embedding vector.
+    pub fn from_papers<I>(papers: I) -> Result<EmbeddingIndex, IndexError>
+    where
+        I: IntoIterator<Item = FullPaper>,
+    {
+        let mut index = EmbeddingIndex::new();
+        for paper in papers {
+            index.insert_paper(paper)?;
+        }
+        Ok(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// The dimension of the indexed vectors, or 0 if the index is empty.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// The model of the indexed paper embeddings, if known.
+    pub fn model(&self) -> Option<&EmbeddingModel> {
+        self.model.as_ref()
+    }
+
+    fn check_dim(&self, found: usize) -> Result<(), DimensionError> {
+        if self.dim != found {
+            return Err(DimensionError { expected: self.dim, found });
+        }
+        Ok(())
+    }
+
+    /// Indexes `vector` for the paper `id`. The first inserted
+    /// vector sets the dimension of the index, empty vectors are rejected.
+    pub fn insert(&mut self, id: String, vector: &[f32]) -> Result<(), DimensionError> {
+        // An empty index expects any dimension from 1 on.
+        if vector.is_empty() {
+            return Err(DimensionError { expected: self.dim.max(1), found: 0 });
+        }
+        if self.is_empty() {
+            self.dim = vector.len();
+        }
+        self.check_dim(vector.len())?;
+        let start = self.vectors.len();
+        self.vectors.extend_from_slice(vector);
+        normalize(&mut self.vectors[start..]);
+        self.ids.push(id);
+        Ok(())
+    }
+
+    /// Indexes the embedding of `paper`, returning `false` if it has no paper ID
+    /// or no embedding vector. Fails if the embedding model is not the one of
+    /// the papers already indexed.
+    pub fn insert_paper(&mut self, paper: FullPaper) -> Result<bool, IndexError> {
+        let id = match paper.base.info.paper_id {
+            Some(ref id) => id.clone(),
+            None => return Ok(false),
+        };
+        let embedding = CompactEmbedding::from(paper);
+        let vector = match embedding.vector {
+            Some(vector) => vector,
+            None => return Ok(false),
+        };
+        if let (Some(expected), Some(found)) = (&self.model, &embedding.model) {
+            if expected != found {
+                let (expected, found) = (expected.clone(), found.clone());
+                return Err(IndexError::Model { expected, found });
+            }
+        }
+        self.insert(id, &vector)?;
+        if self.model.is_none() {
+            self.model = embedding.model;
+        }
+        Ok(true)
+    }
+
+    /// The vector indexed for `id`, normalized.
+    pub fn get(&self, id: &str) -> Option<&[f32]> {
+        let i = self.ids.iter().position(|other| other == id)?;
+        Some(&self.vectors[i * self.dim..(i + 1) * self.dim])
+    }
+
+    /// The `k` indexed papers most similar to `query`, from the most similar.
+    pub fn nearest(&self, query: &[f32], k: usize) -> Result<Vec<Neighbor<'_>>, DimensionError> {
+        if self.is_empty() || k == 0 {
+            return Ok(Vec::new());
+        }
+        self.check_dim(query.len())?;
+        let mut query = query.to_vec();
+        normalize(&mut query);
+        Ok(self.top(&query, k, None))
+    }
+
+    /// The `k` indexed papers most similar to the indexed paper `id`, excluding it.
+    pub fn similar_to(&self, id: &str, k: usize) -> Option<Vec<Neighbor<'_>>> {
+        let query = self.get(id)?;
+        Some(self.top(query, k, Some(id)))
+    }
+
+    /// The `k` highest similarities to the normalized `query`.
+    fn top(&self, query: &[f32], k: usize, exclude: Option<&str>) -> Vec<Neighbor<'_>> {
+        if self.dim == 0 {
+            return Vec::new();
+        }
+        let mut neighbors: Vec<_> = self
+            .ids
+            .iter()
+            .zip(self.vectors.chunks_exact(self.dim))
+            .filter(|(id, _)| Some(id.as_str()) != exclude)
+            .map(|(id, vector)| Neighbor { id, similarity: dot(query, vector) })
+            .collect();
+        // NaN similarities, from NaN vectors, are ranked last.
+        let by_similarity = |a: &Neighbor<'_>, b: &Neighbor<'_>| {
+            b.similarity
+                .partial_cmp(&a.similarity)
+                .unwrap_or_else(|| a.similarity.is_nan().cmp(&b.similarity.is_nan()))
+        };
+        if k < neighbors.len() {
+            neighbors.select_nth_unstable_by(k, by_similarity);
+            neighbors.truncate(k);
+        }
+        neighbors.sort_by(by_similarity);
+        neighbors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn cosine_is_scale_invariant() {
+        assert_eq!(dot(&[1.0, 2.0], &[3.0, 4.0]), 11.0);
+        assert!((cosine(&[1.0, 0.0], &[5.0, 5.0]) - 0.5f32.sqrt()).abs() < 1e-6);
+        assert_eq!(cosine(&[0.0, 0.0], &[1.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    fn nearest_neighbors_are_ranked() {
+        let mut index = EmbeddingIndex::new();
+        index.insert("x".to_owned(), &[1.0, 0.0]).unwrap();
+        index.insert("y".to_owned(), &[0.0, 2.0]).unwrap();
+        index.insert("xy".to_owned(), &[3.0, 3.0]).unwrap();
+        assert_eq!(
+            index.insert("z".to_owned(), &[1.0]),
+            Err(DimensionError { expected: 2, found: 1 })
+        );
+
+        let ids = |neighbors: Vec<Neighbor<'_>>| -> Vec<String> {
+            neighbors.iter().map(|n| n.id.to_owned()).collect()
+        };
+        assert_eq!(ids(index.nearest(&[1.0, 0.1], 2).unwrap()), ["x", "xy"]);
+        assert_eq!(ids(index.similar_to("y", 5).unwrap()), ["xy", "x"]);
+        assert!(index.nearest(&[1.0, 0.0, 0.0], 1).is_err());
+    }
+
+    #[test]
+    fn empty_vectors_are_rejected() {
+        let mut index = EmbeddingIndex::new();
+        assert_eq!(
+            index.insert("a".to_owned(), &[]),
+            Err(DimensionError { expected: 1, found: 0 })
+        );
+        assert!(index.is_empty());
+        assert_eq!(index.nearest(&[], 1), Ok(Vec::new()));
+
+        let paper: FullPaper =
+            serde_json::from_value(json!({"paperId": "b", "embedding": {"vector": []}})).unwrap();
+        assert!(index.insert_paper(paper).is_err());
+        assert_eq!(index.similar_to("b", 1), None);
+    }
+
+    #[test]
+    fn embeddings_of_other_models_are_rejected() {
+        let paper = |id: &str, model: &str| -> FullPaper {
+            let embedding = json!({"model": model, "vector": [1.0, 0.0]});
+            serde_json::from_value(json!({"paperId": id, "embedding": embedding})).unwrap()
+        };
+        let mut index = EmbeddingIndex::new();
+        assert_eq!(index.insert_paper(paper("a", "specter@v0.1.1")), Ok(true));
+        assert_eq!(index.model(), Some(&EmbeddingModel::SpecterV1));
+        assert_eq!(
+            index.insert_paper(paper("b", "specter_v2")),
+            Err(IndexError::Model {
+                expected: EmbeddingModel::SpecterV1,
+                found: EmbeddingModel::SpecterV2
+            })
+        );
+        assert_eq!(index.len(), 1);
+    }
+}
@@ -0,0 +1,628 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::v1::definition::{Author, FullPaper};
+use crate::v1::parameter::{AuthorField, FullPaperField};
+
+// Distinguishes the temporary files of concurrent saves in the same process.
+static TMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum StoreError {
+    /// Reading or writing the store file failed.
+    #[error("failed to access store file: {source}")]
+    Io {
+        #[from]
+        source: io::Error,
+    },
+
+    /// The store file is not valid.
+    #[error("invalid store file: {source}")]
+    Json {
+        #[from]
+        source: serde_json::Error,
+    },
+}
+
+/// A record merged from any number of partial responses.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+struct Record {
+    // The merged top level fields, as returned by the API.
+    data: Map<String, Value>,
+    // Top level field names mapped to the UNIX time, in seconds, of their last update.
+    updated: BTreeMap<String, u64>,
+}
+
+impl Record {
+    /// Merges the top level fields of `object` into the record.
+    ///
+    /// If `requested` is `None`, missing, `null` and empty list fields are
+    /// assumed to have not been requested, so they don't overwrite stored
+    /// data. Otherwise, the `requested` fields are all updated, including
+    /// the ones that are missing from `object`.
+    fn merge(
+        &mut self,
+        object: Map<String, Value>,
+        requested: Option<&BTreeSet<String>>,
+        now: u64,
+    ) {
+        if let Some(requested) = requested {
+            for name in requested.iter().filter(|name| !object.contains_key(*name)) {
+                self.data.remove(name);
+                self.updated.insert(name.clone(), now);
+            }
+        }
+        for (name, value) in object {
+            let provided = match requested {
+                Some(requested) => requested.contains(&name),
+                None => !is_empty(&value),
+            };
+            if provided {
+                self.updated.insert(name.clone(), now);
+                self.data.insert(name, value);
+            }
+        }
+    }
+
+    fn is_stale(&self, field: &str, max_age: Duration, now: u64) -> bool {
+        let updated = self.updated.get(field);
+        updated.map_or(true, |updated| now.saturating_sub(*updated) >= max_age.as_secs())
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(array) => array.is_empty(),
+        _ => false,
+    }
+}
+
+/// The top level field name of a dotted field, `authors.name` becomes `authors`.
+fn field_name(field: &impl Display) -> String {
+    let field = field.to_string();
+    match field.split_once('.') {
+        Some((name, _)) => name.to_owned(),
+        None => field,
+    }
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs())
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+struct Table {
+    records: BTreeMap<String, Record>,
+}
+
+impl Table {
+    fn insert<T>(
+        &mut self,
+        id_field: &str,
+        record: &T,
+        requested: Option<&BTreeSet<String>>,
+        now: u64,
+    ) -> bool
+    where
+        T: Serialize,
+    {
+        let object = match serde_json::to_value(record) {
+            Ok(Value::Object(object)) => object,
+            _ => return false,
+        };
+        let id = match object.get(id_field) {
+            Some(Value::String(id)) => id.clone(),
+            _ => return false,
+        };
+        self.records.entry(id).or_default().merge(object, requested, now);
+        true
+    }
+
+    fn get<T: DeserializeOwned>(&self, id: &str) -> Option<T> {
+        let record = self.records.get(id)?;
+        let data = Value::Object(record.data.clone());
+        Some(serde_json::from_value(data).expect("record must have been validated"))
+    }
+
+    fn updated_at(&self, id: &str, field: &impl Display) -> Option<SystemTime> {
+        let updated = self.records.get(id)?.updated.get(&field_name(field))?;
+        Some(UNIX_EPOCH + Duration::from_secs(*updated))
+    }
+
+    /// Records with any of `fields` missing or older than `max_age`,
+    /// mapped to the fields that must be refreshed.
+    fn stale<F>(&self, fields: &[F], max_age: Duration, now: u64) -> BTreeMap<&str, Vec<F>>
+    where
+        F: Copy + Display,
+    {
+        let mut stale = BTreeMap::new();
+        for (id, record) in &self.records {
+            let fields =
+                fields.iter().filter(|field| record.is_stale(&field_name(*field), max_age, now));
+            let fields: Vec<_> = fields.copied().collect();
+            if !fields.is_empty() {
+                stale.insert(id.as_str(), fields);
+            }
+        }
+        stale
+    }
+
+    fn validate<T: DeserializeOwned>(&self) -> Result<(), serde_json::Error> {
+        for record in self.records.values() {
+            serde_json::from_value::<T>(Value::Object(record.data.clone()))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+struct Tables {
+    papers: Table,
+    authors: Table,
+}
+
+/// A local, file-backed store of [`FullPaper`] and [`Author`] records,
+/// keyed by their Semantic Scholar ID.
+///
+/// Records fetched with different sets of fields are merged into a single
+/// record, and the time of the last update of each top level field is tracked,
+/// so that only missing or stale fields are requested when refreshing them.
+/// Nested fields, such as `authors.name`, are tracked and replaced as a
+/// whole by their top level field, `authors`.
+///
+/// Changes are kept in memory until the store is [saved](Store::save).
+#[derive(Clone, Debug)]
+pub struct Store {
+    path: PathBuf,
+    tables: Tables,
+}
+
+impl Store {
+    /// Opens the store file at `path`, or creates an empty store if it doesn't exist.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Store, StoreError> {
+        let path = path.into();
+        let tables: Tables = match fs::read(&path) {
+            Ok(contents) => serde_json::from_slice(&contents)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Tables::default(),
+            Err(err) => return Err(err.into()),
+        };
+        tables.papers.validate::<FullPaper>()?;
+        tables.authors.validate::<Author>()?;
+        Ok(Store { path, tables })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes the store to its file.
+    pub fn save(&self) -> Result<(), StoreError> {
+        // Write to a temporary file first, so that a failed
+        // write never corrupts the previously saved store.
+        // It is named after the store file, the process and a counter, so that
+        // concurrent saves, or stores of the same name with different extensions,
+        // never write to the same file.
+        let count = TMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(format!(".{}.{}.tmp", std::process::id(), count));
+        let tmp = PathBuf::from(tmp);
+        let mut file = io::BufWriter::new(fs::File::create(&tmp)?);
+        serde_json::to_writer(&mut file, &self.tables)?;
+        file.flush()?;
+        file.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// Merges `paper` into the stored record with the same ID.
+    ///
+    /// Fields that are `None` or empty are assumed to have not been requested,
+    /// and are left as stored. Returns `false` if `paper` has no ID.
+    pub fn insert_paper(&mut self, paper: &FullPaper) -> bool {
+        self.tables.papers.insert("paperId", paper, None, unix_time(SystemTime::now()))
+    }
+
+    /// Merges `paper`, fetched with `fields`, into the stored record with the same ID.
+    ///
+    /// Unlike [`Store::insert_paper`], all of the requested `fields` are updated,
+    /// so a requested field that is `None` or empty is stored as such.
+    pub fn insert_paper_with(&mut self, paper: &FullPaper, fields: &[FullPaperField]) -> bool {
+        let requested = fields.iter().map(field_name).collect();
+        let now = unix_time(SystemTime::now());
+        self.tables.papers.insert("paperId", paper, Some(&requested), now)
+    }
+
+    /// The merged record of the paper with `paper_id`.
+    pub fn paper(&self, paper_id: &str) -> Option<FullPaper> {
+        self.tables.papers.get(paper_id)
+    }
+
+    /// When `field` of the paper with `paper_id` was last updated.
+    pub fn paper_updated_at(&self, paper_id: &str, field: FullPaperField) -> Option<SystemTime> {
+        self.tables.papers.updated_at(paper_id, &field)
+    }
+
+    pub fn remove_paper(&mut self, paper_id: &str) -> bool {
+        self.tables.papers.records.remove(paper_id).is_some()
+    }
+
+    pub fn paper_ids(&self) -> impl Iterator<Item = &str> {
+        self.tables.papers.records.keys().map(String::as_str)
+    }
+
+    /// IDs of the papers with any of `fields` missing or older than `max_age`.
+    pub fn stale_papers(&self, fields: &[FullPaperField], max_age: Duration) -> Vec<&str> {
+        let now = unix_time(SystemTime::now());
+        self.tables.papers.stale(fields, max_age, now).into_keys().collect()
+    }
+
+    /// Merges `author` into the stored record with the same ID.
+    /// See [`Store::insert_paper`].
+    pub fn insert_author(&mut self, author: &Author) -> bool {
+        self.tables.authors.insert("authorId", author, None, unix_time(SystemTime::now()))
+    }
+
+    /// Merges `author`, fetched with `fields`, into the stored record with the same ID.
+    /// See [`Store::insert_paper_with`].
+    pub fn insert_author_with(&mut self, author: &Author, fields: &[AuthorField]) -> bool {
+        let requested = fields.iter().map(field_name).collect();
+        let now = unix_time(SystemTime::now());
+        self.tables.authors.insert("authorId", author, Some(&requested), now)
+    }
+
+    /// The merged record of the author with `author_id`.
+    pub fn author(&self, author_id: &str) -> Option<Author> {
+        self.tables.authors.get(author_id)
+    }
+
+    /// When `field` of the author with `author_id` was last updated.
+    pub fn author_updated_at(&self, author_id: &str, field: AuthorField) -> Option<SystemTime> {
+        self.tables.authors.updated_at(author_id, &field)
+    }
+
+    pub fn remove_author(&mut self, author_id: &str) -> bool {
+        self.tables.authors.records.remove(author_id).is_some()
+    }
+
+    pub fn author_ids(&self) -> impl Iterator<Item = &str> {
+        self.tables.authors.records.keys().map(String::as_str)
+    }
+
+    /// IDs of the authors with any of `fields` missing or older than `max_age`.
+    pub fn stale_authors(&self, fields: &[AuthorField], max_age: Duration) -> Vec<&str> {
+        let now = unix_time(SystemTime::now());
+        self.tables.authors.stale(fields, max_age, now).into_keys().collect()
+    }
+
+    /// Stale papers and the fields that must be refreshed, always including the ID.
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn stale_paper_fields(
+        &self,
+        fields: &[FullPaperField],
+        max_age: Duration,
+    ) -> Vec<(String, Vec<FullPaperField>)> {
+        use crate::v1::parameter::PaperInfoField;
+
+        let now = unix_time(SystemTime::now());
+        let stale = self.tables.papers.stale(fields, max_age, now).into_iter();
+        let with_id = |mut fields: Vec<_>| {
+            fields.push(FullPaperField::from(PaperInfoField::PaperId));
+            fields
+        };
+        stale.map(|(id, fields)| (id.to_owned(), with_id(fields))).collect()
+    }
+
+    /// Stale authors and the fields that must be refreshed, always including the ID.
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn stale_author_fields(
+        &self,
+        fields: &[AuthorField],
+        max_age: Duration,
+    ) -> Vec<(String, Vec<AuthorField>)> {
+        use crate::v1::parameter::AuthorInfoField;
+
+        let now = unix_time(SystemTime::now());
+        let stale = self.tables.authors.stale(fields, max_age, now).into_iter();
+        let with_id = |mut fields: Vec<_>| {
+            fields.push(AuthorField::from(AuthorInfoField::AuthorId));
+            fields
+        };
+        stale.map(|(id, fields)| (id.to_owned(), with_id(fields))).collect()
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+type RefreshApiError<C> = crate::error::ApiError<
+    crate::v1::error::ResponseError,
+    <C as crate::client::BaseClient>::Error,
+>;
+
+/// An error returned while refreshing stored records.
+#[cfg(any(feature = "blocking", feature = "async"))]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RefreshError<C: std::error::Error> {
+    /// The requested fields would be rejected by the endpoint.
+    #[error("invalid refreshed fields: {source}")]
+    Fields {
+        #[from]
+        source: crate::v1::error::FieldsError,
+    },
+
+    /// The API request failed.
+    #[error(transparent)]
+    Api {
+        #[from]
+        source: crate::error::ApiError<crate::v1::error::ResponseError, C>,
+    },
+}
+
+// The refreshed fields are validated before any request, and the
+// stale fields of a record are a subset of them plus the ID field.
+#[cfg(any(feature = "blocking", feature = "async"))]
+static FIELDS_MSG: &str = "stale fields are valid once the refreshed fields are";
+
+// There is no batch endpoint for papers or authors in this API version,
+// so stale records are refreshed one request at a time.
+#[cfg(feature = "blocking")]
+mod blocking {
+    use super::*;
+    use crate::client::Client;
+    use crate::v1::endpoint::{GetAuthor, GetPaper};
+    use crate::v1::query_params::{AuthorParams, PaperParams};
+
+    impl Store {
+        /// Fetches the missing or stale `fields` of every stored paper that
+        /// were last updated more than `max_age` ago, requesting only those.
+        /// Returns the number of refreshed papers.
+        ///
+        /// Fails without any request if the endpoint would reject `fields`.
+        /// Otherwise, stops at the first error returned by the API, keeping
+        /// the papers refreshed so far. The store is not saved.
+        pub fn refresh_papers<C>(
+            &mut self,
+            client: &C,
+            fields: &[FullPaperField],
+            max_age: Duration,
+        ) -> Result<usize, RefreshError<C::Error>>
+        where
+            C: Client,
+            RefreshApiError<C>: From<C::Error>,
+        {
+            PaperParams::new(Some(fields.iter().copied()))?;
+            let stale = self.stale_paper_fields(fields, max_age);
+            for (paper_id, fields) in &stale {
+                let endpoint = GetPaper::new(
+                    PaperParams::new(Some(fields.clone())).expect(FIELDS_MSG),
+                    paper_id.clone(),
+                );
+                let paper: FullPaper = endpoint.query(client)?;
+                self.insert_paper_with(&paper, fields);
+            }
+            Ok(stale.len())
+        }
+
+        /// Fetches the missing or stale `fields` of every stored author.
+        /// See [`Store::refresh_papers`].
+        pub fn refresh_authors<C>(
+            &mut self,
+            client: &C,
+            fields: &[AuthorField],
+            max_age: Duration,
+        ) -> Result<usize, RefreshError<C::Error>>
+        where
+            C: Client,
+            RefreshApiError<C>: From<C::Error>,
+        {
+            AuthorParams::new(Some(fields.iter().copied()))?;
+            let stale = self.stale_author_fields(fields, max_age);
+            for (author_id, fields) in &stale {
+                let endpoint = GetAuthor::new(
+                    AuthorParams::new(Some(fields.clone())).expect(FIELDS_MSG),
+                    author_id.clone(),
+                );
+                let author: Author = endpoint.query(client)?;
+                self.insert_author_with(&author, fields);
+            }
+            Ok(stale.len())
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+    use super::*;
+    use crate::client::AsyncClient;
+    use crate::v1::endpoint::{GetAuthor, GetPaper};
+    use crate::v1::query_params::{AuthorParams, PaperParams};
+
+    impl Store {
+        /// Async version of [`Store::refresh_papers`].
+        pub async fn refresh_papers_async<C>(
+            &mut self,
+            client: &C,
+            fields: &[FullPaperField],
+            max_age: Duration,
+        ) -> Result<usize, RefreshError<C::Error>>
+        where
+            C: AsyncClient + Sync,
+            RefreshApiError<C>: From<C::Error>,
+        {
+            PaperParams::new(Some(fields.iter().copied()))?;
+            let stale = self.stale_paper_fields(fields, max_age);
+            for (paper_id, fields) in &stale {
+                let endpoint = GetPaper::new(
+                    PaperParams::new(Some(fields.clone())).expect(FIELDS_MSG),
+                    paper_id.clone(),
+                );
+                let paper: FullPaper = endpoint.query_async(client).await?;
+                self.insert_paper_with(&paper, fields);
+            }
+            Ok(stale.len())
+        }
+
+        /// Async version of [`Store::refresh_authors`].
+        pub async fn refresh_authors_async<C>(
+            &mut self,
+            client: &C,
+            fields: &[AuthorField],
+            max_age: Duration,
+        ) -> Result<usize, RefreshError<C::Error>>
+        where
+            C: AsyncClient + Sync,
+            RefreshApiError<C>: From<C::Error>,
+        {
+            AuthorParams::new(Some(fields.iter().copied()))?;
+            let stale = self.stale_author_fields(fields, max_age);
+            for (author_id, fields) in &stale {
+                let endpoint = GetAuthor::new(
+                    AuthorParams::new(Some(fields.clone())).expect(FIELDS_MSG),
+                    author_id.clone(),
+                );
+                let author: Author = endpoint.query_async(client).await?;
+                self.insert_author_with(&author, fields);
+            }
+            Ok(stale.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::definition::Tldr;
+    use crate::v1::parameter::{BasePaperField, PaperInfoField};
+
+    fn paper() -> FullPaper {
+        let mut paper = FullPaper::default();
+        paper.base.info.paper_id = Some("p1".to_owned());
+        paper
+    }
+
+    #[test]
+    fn partial_papers_are_merged() {
+        let mut store = Store { path: PathBuf::new(), tables: Tables::default() };
+        let mut title = paper();
+        title.base.info.title = Some("Title".to_owned());
+        let mut tldr = paper();
+        tldr.tldr = Some(Tldr { model: None, text: Some("Summary".to_owned()) });
+
+        assert!(store.insert_paper(&title));
+        assert!(store.insert_paper(&tldr));
+        assert!(!store.insert_paper(&FullPaper::default()));
+
+        let merged = store.paper("p1").unwrap();
+        assert_eq!(merged.base.info.title.as_deref(), Some("Title"));
+        assert_eq!(merged.tldr.and_then(|tldr| tldr.text).as_deref(), Some("Summary"));
+    }
+
+    #[test]
+    fn missing_and_old_fields_are_stale() {
+        let mut store = Store { path: PathBuf::new(), tables: Tables::default() };
+        let title = FullPaperField::from(PaperInfoField::Title);
+        let count = FullPaperField::from(BasePaperField::CitationCount);
+        let mut paper = paper();
+        paper.base.info.title = Some("Title".to_owned());
+        store.insert_paper(&paper);
+
+        let hour = Duration::from_secs(60 * 60);
+        assert!(store.stale_papers(&[title], hour).is_empty());
+        assert_eq!(store.stale_papers(&[title, count], hour), ["p1"]);
+        assert_eq!(store.stale_papers(&[title], Duration::ZERO), ["p1"]);
+
+        // A requested field is up to date even if the API returned nothing.
+        store.insert_paper_with(&paper, &[count]);
+        assert!(store.stale_papers(&[title, count], hour).is_empty());
+    }
+
+    #[test]
+    fn store_is_saved_and_reopened() {
+        let path = std::env::temp_dir().join(format!("scholars-store-{}.json", std::process::id()));
+        let mut store = Store::open(&path).unwrap();
+        let mut author = Author::default();
+        author.info.author_id = Some("a1".to_owned());
+        author.h_index = Some(10);
+        store.insert_author(&author);
+        store.save().unwrap();
+
+        let reopened = Store::open(&path).unwrap();
+        assert_eq!(reopened.author("a1"), Some(author));
+        assert!(reopened.author_updated_at("a1", AuthorField::HIndex).is_some());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn concurrent_saves_use_their_own_temporary_files() {
+        let dir = std::env::temp_dir().join(format!("scholars-store-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let threads: Vec<_> = ["store.json", "store.bin", "store.json"]
+            .iter()
+            .map(|name| {
+                let mut store = Store::open(dir.join(name)).unwrap();
+                store.insert_paper(&paper());
+                std::thread::spawn(move || store.save())
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap().unwrap();
+        }
+
+        let mut files: Vec<_> =
+            fs::read_dir(&dir).unwrap().map(|e| e.unwrap().file_name()).collect();
+        files.sort();
+        assert_eq!(files, ["store.bin", "store.json"]);
+        assert!(Store::open(dir.join("store.bin")).unwrap().paper("p1").is_some());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn refreshed_fields_are_validated_before_any_request() {
+        use bytes::Bytes;
+        use http::{request::Builder, Response};
+        use url::{ParseError, Url};
+
+        use crate::client::{BaseClient, Client};
+        use crate::v1::error::FieldsError;
+        use crate::v1::parameter::EmbeddingVersion;
+
+        struct Unreachable;
+
+        impl BaseClient for Unreachable {
+            type Error = http::Error;
+
+            fn endpoint(&self, endpoint: &str) -> Result<Url, ParseError> {
+                Url::parse(endpoint)
+            }
+        }
+
+        impl Client for Unreachable {
+            fn send(&self, _: Builder, _: Vec<u8>) -> Result<Response<Bytes>, http::Error> {
+                unreachable!("no request is sent for invalid fields")
+            }
+        }
+
+        let mut store = Store { path: PathBuf::new(), tables: Tables::default() };
+        store.insert_paper(&paper());
+        let fields = [
+            FullPaperField::Embedding(None),
+            FullPaperField::Embedding(Some(EmbeddingVersion::SpecterV2)),
+        ];
+        let err = store.refresh_papers(&Unreachable, &fields, Duration::ZERO).unwrap_err();
+        assert!(matches!(
+            err,
+            RefreshError::Fields { source: FieldsError::Exclusive { endpoint: "paper", .. } }
+        ));
+    }
+}
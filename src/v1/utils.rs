@@ -14,7 +14,7 @@ const PAPER_INFO_FIELDS: [PaperInfoField; 6] = [
 
 const AUTHOR_INFO_FIELDS: [AuthorInfoField; 2] = [AuthorInfoField::AuthorId, AuthorInfoField::Name];
 
-const BASE_PAPER_FIELDS: [BasePaperField; 7] = [
+const BASE_PAPER_FIELDS: [BasePaperField; 14] = [
     // BasePaperField::Info(PaperInfoField)
     BasePaperField::ExternalIds,
     BasePaperField::Abstract,
@@ -23,6 +23,13 @@ const BASE_PAPER_FIELDS: [BasePaperField; 7] = [
     BasePaperField::InfluentialCitationCount,
     BasePaperField::IsOpenAccess,
     BasePaperField::FieldsOfStudy,
+    BasePaperField::PublicationDate,
+    BasePaperField::PublicationTypes,
+    BasePaperField::Journal,
+    BasePaperField::PublicationVenue,
+    BasePaperField::OpenAccessPdf,
+    BasePaperField::S2FieldsOfStudy,
+    BasePaperField::CitationStyles,
 ];
 
 const AUTHOR_FIELDS: [AuthorField; 8] = [
@@ -47,9 +54,10 @@ const PAPER_FIELDS: [PaperField; 3] = [
 const FULL_PAPER_FIELDS: [FullPaperField; 2] = [
     // FullPaperField::Base(BasePaperField)
     // FullPaperField::Authors(Option<AuthorField>)
-    // FullPaperField::Citations(Option<PaperInfoField>)
-    // FullPaperField::References(Option<PaperInfoField>)
-    FullPaperField::Embedding,
+    // FullPaperField::Citations(Option<BasePaperField>)
+    // FullPaperField::References(Option<BasePaperField>)
+    // FullPaperField::Embedding(Some(EmbeddingVersion))
+    FullPaperField::Embedding(None),
     FullPaperField::Tldr,
 ];
 
@@ -102,8 +110,8 @@ pub fn all_paper_with_links_fields() -> impl Iterator<Item = PaperWithLinksField
     all_base_paper_fields()
         .map(PaperWithLinksField::from)
         .chain(all_author_info_fields().map(PaperWithLinksField::from))
-        .chain(all_paper_info_fields().map(PaperWithLinksField::references_from))
-        .chain(all_paper_info_fields().map(PaperWithLinksField::citations_from))
+        .chain(all_base_paper_fields().map(PaperWithLinksField::references_from))
+        .chain(all_base_paper_fields().map(PaperWithLinksField::citations_from))
 }
 
 fn exclusive_full_paper_fields() -> impl Iterator<Item = FullPaperField> {
@@ -115,8 +123,8 @@ pub fn all_full_paper_fields() -> impl Iterator<Item = FullPaperField> {
     exclusive_full_paper_fields()
         .chain(all_author_fields().map(FullPaperField::from))
         .chain(all_base_paper_fields().map(FullPaperField::from))
-        .chain(all_paper_info_fields().map(FullPaperField::citations_from))
-        .chain(all_paper_info_fields().map(FullPaperField::references_from))
+        .chain(all_base_paper_fields().map(FullPaperField::citations_from))
+        .chain(all_base_paper_fields().map(FullPaperField::references_from))
 }
 
 /// An [`Iterator`] with entries for all possible variants of [`PaperField`]
@@ -0,0 +1,86 @@
+//! Checks of the requested fields against the rules of each endpoint.
+//!
+//! The field types already restrict which fields each endpoint accepts and
+//! how deep they can be nested, these rules cover what the types can't.
+
+use std::collections::BTreeSet;
+
+use crate::v1::error::FieldsError;
+
+#[derive(Clone, Copy, Debug)]
+pub(in crate::v1) struct FieldRules {
+    endpoint: &'static str,
+    // Pairs of fields that can't be requested together.
+    exclusive: &'static [(&'static str, &'static str)],
+}
+
+macro_rules! define_rules {
+    ( $($rules:ident : $endpoint:literal, [$(($a:literal, $b:literal)),*];)* ) => {$(
+        pub(in crate::v1) const $rules: FieldRules = FieldRules {
+            endpoint: $endpoint,
+            exclusive: &[$(($a, $b)),*],
+        };
+    )*};
+}
+
+// A paper has a single `embedding` object, so only one
+// model version can be requested, `embedding` being SPECTER v1.
+define_rules! {
+    PAPER: "paper", [
+        ("embedding", "embedding.specter_v2"),
+        ("embedding.specter_v1", "embedding.specter_v2")
+    ];
+    PAPER_SEARCH: "paper search", [];
+    PAPER_AUTHORS: "paper authors", [];
+    PAPER_CITATIONS: "paper citations", [];
+    PAPER_REFERENCES: "paper references", [];
+    AUTHOR: "author", [];
+    AUTHOR_SEARCH: "author search", [];
+    AUTHOR_PAPERS: "author papers", [];
+}
+
+impl FieldRules {
+    /// Checks the rendered names of a field list.
+    pub(in crate::v1) fn validate<I>(&self, names: I) -> Result<(), FieldsError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let endpoint = self.endpoint;
+        let names: BTreeSet<String> = names.into_iter().collect();
+        let mut exclusive = self.exclusive.iter();
+        if let Some((a, b)) = exclusive.find(|(a, b)| names.contains(*a) && names.contains(*b)) {
+            let (field, other) = ((*a).to_owned(), (*b).to_owned());
+            return Err(FieldsError::Exclusive { endpoint, field, other });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| (*name).to_owned()).collect()
+    }
+
+    #[test]
+    fn params_keep_accepting_papers_and_empty_lists() {
+        use crate::v1::parameter::AuthorWithPapersField;
+        use crate::v1::query_params::AuthorParams;
+
+        let papers = AuthorWithPapersField::Papers(None);
+        assert_eq!(papers.to_string(), "papers.title");
+        assert!(AuthorParams::new(Some([papers])).is_ok());
+        let params = AuthorParams::new(Some(Vec::<AuthorWithPapersField>::new())).unwrap();
+        assert_eq!(params, AuthorParams::new(None::<Vec<AuthorWithPapersField>>).unwrap());
+    }
+
+    #[test]
+    fn one_embedding_version_is_requested() {
+        let err = PAPER.validate(names(&["embedding", "embedding.specter_v2"])).unwrap_err();
+        let (field, other) = ("embedding".into(), "embedding.specter_v2".into());
+        assert_eq!(err, FieldsError::Exclusive { endpoint: "paper", field, other });
+        assert_eq!(PAPER.validate(names(&["title", "embedding.specter_v2"])), Ok(()));
+    }
+}